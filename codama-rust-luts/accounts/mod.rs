@@ -5,6 +5,14 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub mod r#config;
+pub mod r#lut_counter;
+pub mod r#lut_registry;
+pub mod r#lut_set;
 pub mod r#user_address_lookup_table;
 
+pub use self::r#config::*;
+pub use self::r#lut_counter::*;
+pub use self::r#lut_registry::*;
+pub use self::r#lut_set::*;
 pub use self::r#user_address_lookup_table::*;
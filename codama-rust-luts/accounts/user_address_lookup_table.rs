@@ -5,6 +5,8 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::codama_rust_luts::types::LutDelegate;
+use crate::codama_rust_luts::types::LutStatus;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_pubkey::Pubkey;
@@ -27,13 +29,40 @@ pub struct UserAddressLookupTable {
     )]
     pub address_lookup_table: Pubkey,
     pub last_updated_slot: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub creator: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_authority: Pubkey,
+    pub delegates: [LutDelegate; 4],
+    pub status: LutStatus,
+    pub deactivated_slot: u64,
+    pub burst_fill: bool,
+    pub ready_at_slot: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub previous_address_lookup_table: Pubkey,
+    pub generation: u64,
+    pub registered: bool,
+    pub version: u8,
+    pub label: String,
+    pub metadata_uri: String,
+    pub namespace: [u8; 32],
+    pub allowed_owners: [Pubkey; 4],
+    pub allowed_owner_count: u8,
+    pub counted: bool,
 }
 
 pub const USER_ADDRESS_LOOKUP_TABLE_DISCRIMINATOR: [u8; 8] = [28, 183, 129, 166, 216, 32, 90, 100];
 
 impl UserAddressLookupTable {
-    pub const LEN: usize = 97;
-
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
         let mut data = data;
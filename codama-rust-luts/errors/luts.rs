@@ -22,6 +22,96 @@ pub enum LutsError {
     /// 6003 - No new addresses to add
     #[error("No new addresses to add")]
     NoNewAddresses = 0x1773,
+    /// 6004 - Signer is not the pending authority
+    #[error("Signer is not the pending authority")]
+    NotPendingAuthority = 0x1774,
+    /// 6005 - Signer is neither the LUT owner nor an active delegate
+    #[error("Signer is neither the LUT owner nor an active delegate")]
+    UnauthorizedExtender = 0x1775,
+    /// 6006 - Maximum delegates exceeded
+    #[error("Maximum delegates exceeded")]
+    MaxDelegatesExceeded = 0x1776,
+    /// 6007 - Delegate not found
+    #[error("Delegate not found")]
+    DelegateNotFound = 0x1777,
+    /// 6008 - Delegate expiry slot must be in the future
+    #[error("Delegate expiry slot must be in the future")]
+    InvalidDelegateExpiry = 0x1778,
+    /// 6009 - LUT is frozen and can no longer be modified
+    #[error("LUT is frozen and can no longer be modified")]
+    LutFrozen = 0x1779,
+    /// 6010 - LUT is deactivated
+    #[error("LUT is deactivated")]
+    LutDeactivated = 0x177A,
+    /// 6011 - LUT is not in burst-fill mode
+    #[error("LUT is not in burst-fill mode")]
+    NotInBurstFill = 0x177B,
+    /// 6012 - Invalid config parameters
+    #[error("Invalid config parameters")]
+    InvalidConfig = 0x177C,
+    /// 6013 - Maximum LUTs per signer exceeded
+    #[error("Maximum LUTs per signer exceeded")]
+    MaxLutsPerSignerExceeded = 0x177D,
+    /// 6014 - Program is paused
+    #[error("Program is paused")]
+    ProgramPaused = 0x177E,
+    /// 6015 - Signer is not allowed to change the pause state
+    #[error("Signer is not allowed to change the pause state")]
+    NotPauseAuthority = 0x177F,
+    /// 6016 - Treasury account is required when a fee is charged
+    #[error("Treasury account is required when a fee is charged")]
+    MissingTreasury = 0x1780,
+    /// 6017 - Treasury does not match the config
+    #[error("Treasury does not match the config")]
+    InvalidTreasury = 0x1781,
+    /// 6018 - The previous LUT must be closed first
+    #[error("The previous LUT must be closed first")]
+    PreviousLutPending = 0x1782,
+    /// 6019 - Wrapper has no previous LUT
+    #[error("Wrapper has no previous LUT")]
+    NoPreviousLut = 0x1783,
+    /// 6020 - LUT set has no free member slots
+    #[error("LUT set has no free member slots")]
+    LutSetFull = 0x1784,
+    /// 6021 - Wrapper is already a member of the LUT set
+    #[error("Wrapper is already a member of the LUT set")]
+    LutSetMemberExists = 0x1785,
+    /// 6022 - Accounts do not match the LUT set members
+    #[error("Accounts do not match the LUT set members")]
+    InvalidLutSetMember = 0x1786,
+    /// 6023 - id is below the registry's next_id
+    #[error("id is below the registry's next_id")]
+    LutIdBelowNext = 0x1787,
+    /// 6024 - LUT registry is required for a registered LUT
+    #[error("LUT registry is required for a registered LUT")]
+    MissingLutRegistry = 0x1788,
+    /// 6025 - Native LUT still exists; use close_address_lookup_table
+    #[error("Native LUT still exists; use close_address_lookup_table")]
+    LutNotOrphaned = 0x1789,
+    /// 6026 - Wrapper uses the legacy layout; call migrate_wrapper first
+    #[error("Wrapper uses the legacy layout; call migrate_wrapper first")]
+    WrapperNeedsMigration = 0x178A,
+    /// 6027 - Wrapper already uses the current layout
+    #[error("Wrapper already uses the current layout")]
+    WrapperAlreadyMigrated = 0x178B,
+    /// 6028 - Label or metadata URI is too long
+    #[error("Label or metadata URI is too long")]
+    WrapperMetadataTooLong = 0x178C,
+    /// 6029 - Namespace must not be all zeroes
+    #[error("Namespace must not be all zeroes")]
+    InvalidNamespace = 0x178D,
+    /// 6030 - Address is not owned by an allowed program
+    #[error("Address is not owned by an allowed program")]
+    OwnerNotAllowed = 0x178E,
+    /// 6031 - Maximum allowed owners exceeded
+    #[error("Maximum allowed owners exceeded")]
+    MaxAllowedOwnersExceeded = 0x178F,
+    /// 6032 - LUT counter is missing or does not belong to the creator
+    #[error("LUT counter is missing or does not belong to the creator")]
+    MissingLutCounter = 0x1790,
+    /// 6033 - LUT id is the largest possible id; the registry has no next id
+    #[error("LUT id is the largest possible id; the registry has no next id")]
+    LutIdOverflow = 0x1791,
 }
 
 impl From<LutsError> for solana_program_error::ProgramError {
@@ -15,6 +15,8 @@ pub const CLOSE_ADDRESS_LOOKUP_TABLE_DISCRIMINATOR: [u8; 8] = [177, 46, 58, 36,
 pub struct CloseAddressLookupTable {
     pub signer: solana_pubkey::Pubkey,
 
+    pub rent_destination: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub address_lookup_table_program: solana_pubkey::Pubkey,
@@ -23,6 +25,10 @@ pub struct CloseAddressLookupTable {
 
     pub user_address_lookup_table: solana_pubkey::Pubkey,
 
+    pub lut_registry: Option<solana_pubkey::Pubkey>,
+
+    pub lut_counter: Option<solana_pubkey::Pubkey>,
+
     pub rent: solana_pubkey::Pubkey,
 }
 
@@ -36,8 +42,19 @@ impl CloseAddressLookupTable {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
+        if let Some(rent_destination) = self.rent_destination {
+            accounts.push(solana_instruction::AccountMeta::new(
+                rent_destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -54,6 +71,22 @@ impl CloseAddressLookupTable {
             self.user_address_lookup_table,
             false,
         ));
+        if let Some(lut_registry) = self.lut_registry {
+            accounts.push(solana_instruction::AccountMeta::new(lut_registry, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        if let Some(lut_counter) = self.lut_counter {
+            accounts.push(solana_instruction::AccountMeta::new(lut_counter, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.rent, false,
         ));
@@ -99,18 +132,24 @@ impl Default for CloseAddressLookupTableInstructionData {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   2. `[optional]` address_lookup_table_program (default to `AddressLookupTab1e1111111111111111111111111`)
-///   3. `[writable]` address_lookup_table
-///   4. `[writable]` user_address_lookup_table
-///   5. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   1. `[writable, optional]` rent_destination
+///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[optional]` address_lookup_table_program (default to `AddressLookupTab1e1111111111111111111111111`)
+///   4. `[writable]` address_lookup_table
+///   5. `[writable]` user_address_lookup_table
+///   6. `[writable, optional]` lut_registry
+///   7. `[writable, optional]` lut_counter
+///   8. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseAddressLookupTableBuilder {
     signer: Option<solana_pubkey::Pubkey>,
+    rent_destination: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     address_lookup_table_program: Option<solana_pubkey::Pubkey>,
     address_lookup_table: Option<solana_pubkey::Pubkey>,
     user_address_lookup_table: Option<solana_pubkey::Pubkey>,
+    lut_registry: Option<solana_pubkey::Pubkey>,
+    lut_counter: Option<solana_pubkey::Pubkey>,
     rent: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -124,6 +163,15 @@ impl CloseAddressLookupTableBuilder {
         self.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_destination(
+        &mut self,
+        rent_destination: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.rent_destination = rent_destination;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -155,6 +203,18 @@ impl CloseAddressLookupTableBuilder {
         self.user_address_lookup_table = Some(user_address_lookup_table);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lut_registry(&mut self, lut_registry: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.lut_registry = lut_registry;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lut_counter(&mut self, lut_counter: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.lut_counter = lut_counter;
+        self
+    }
     /// `[optional account, default to 'SysvarRent111111111111111111111111111111111']`
     #[inline(always)]
     pub fn rent(&mut self, rent: solana_pubkey::Pubkey) -> &mut Self {
@@ -180,6 +240,7 @@ impl CloseAddressLookupTableBuilder {
     pub fn instruction(&self) -> solana_instruction::Instruction {
         let accounts = CloseAddressLookupTable {
             signer: self.signer.expect("signer is not set"),
+            rent_destination: self.rent_destination,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -192,6 +253,8 @@ impl CloseAddressLookupTableBuilder {
             user_address_lookup_table: self
                 .user_address_lookup_table
                 .expect("user_address_lookup_table is not set"),
+            lut_registry: self.lut_registry,
+            lut_counter: self.lut_counter,
             rent: self.rent.unwrap_or(solana_pubkey::pubkey!(
                 "SysvarRent111111111111111111111111111111111"
             )),
@@ -205,6 +268,8 @@ impl CloseAddressLookupTableBuilder {
 pub struct CloseAddressLookupTableCpiAccounts<'a, 'b> {
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
+    pub rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table_program: &'b solana_account_info::AccountInfo<'a>,
@@ -213,6 +278,10 @@ pub struct CloseAddressLookupTableCpiAccounts<'a, 'b> {
 
     pub user_address_lookup_table: &'b solana_account_info::AccountInfo<'a>,
 
+    pub lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub lut_counter: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub rent: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -223,6 +292,8 @@ pub struct CloseAddressLookupTableCpi<'a, 'b> {
 
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
+    pub rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table_program: &'b solana_account_info::AccountInfo<'a>,
@@ -231,6 +302,10 @@ pub struct CloseAddressLookupTableCpi<'a, 'b> {
 
     pub user_address_lookup_table: &'b solana_account_info::AccountInfo<'a>,
 
+    pub lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub lut_counter: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub rent: &'b solana_account_info::AccountInfo<'a>,
 }
 
@@ -242,10 +317,13 @@ impl<'a, 'b> CloseAddressLookupTableCpi<'a, 'b> {
         Self {
             __program: program,
             signer: accounts.signer,
+            rent_destination: accounts.rent_destination,
             system_program: accounts.system_program,
             address_lookup_table_program: accounts.address_lookup_table_program,
             address_lookup_table: accounts.address_lookup_table,
             user_address_lookup_table: accounts.user_address_lookup_table,
+            lut_registry: accounts.lut_registry,
+            lut_counter: accounts.lut_counter,
             rent: accounts.rent,
         }
     }
@@ -272,8 +350,19 @@ impl<'a, 'b> CloseAddressLookupTableCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.signer.key, true));
+        if let Some(rent_destination) = self.rent_destination {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *rent_destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -290,6 +379,28 @@ impl<'a, 'b> CloseAddressLookupTableCpi<'a, 'b> {
             *self.user_address_lookup_table.key,
             false,
         ));
+        if let Some(lut_registry) = self.lut_registry {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *lut_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        if let Some(lut_counter) = self.lut_counter {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *lut_counter.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.rent.key,
             false,
@@ -310,13 +421,22 @@ impl<'a, 'b> CloseAddressLookupTableCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.signer.clone());
+        if let Some(rent_destination) = self.rent_destination {
+            account_infos.push(rent_destination.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.address_lookup_table_program.clone());
         account_infos.push(self.address_lookup_table.clone());
         account_infos.push(self.user_address_lookup_table.clone());
+        if let Some(lut_registry) = self.lut_registry {
+            account_infos.push(lut_registry.clone());
+        }
+        if let Some(lut_counter) = self.lut_counter {
+            account_infos.push(lut_counter.clone());
+        }
         account_infos.push(self.rent.clone());
         remaining_accounts
             .iter()
@@ -335,11 +455,14 @@ impl<'a, 'b> CloseAddressLookupTableCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[]` system_program
-///   2. `[]` address_lookup_table_program
-///   3. `[writable]` address_lookup_table
-///   4. `[writable]` user_address_lookup_table
-///   5. `[]` rent
+///   1. `[writable, optional]` rent_destination
+///   2. `[]` system_program
+///   3. `[]` address_lookup_table_program
+///   4. `[writable]` address_lookup_table
+///   5. `[writable]` user_address_lookup_table
+///   6. `[writable, optional]` lut_registry
+///   7. `[writable, optional]` lut_counter
+///   8. `[]` rent
 #[derive(Clone, Debug)]
 pub struct CloseAddressLookupTableCpiBuilder<'a, 'b> {
     instruction: Box<CloseAddressLookupTableCpiBuilderInstruction<'a, 'b>>,
@@ -350,10 +473,13 @@ impl<'a, 'b> CloseAddressLookupTableCpiBuilder<'a, 'b> {
         let instruction = Box::new(CloseAddressLookupTableCpiBuilderInstruction {
             __program: program,
             signer: None,
+            rent_destination: None,
             system_program: None,
             address_lookup_table_program: None,
             address_lookup_table: None,
             user_address_lookup_table: None,
+            lut_registry: None,
+            lut_counter: None,
             rent: None,
             __remaining_accounts: Vec::new(),
         });
@@ -364,6 +490,15 @@ impl<'a, 'b> CloseAddressLookupTableCpiBuilder<'a, 'b> {
         self.instruction.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn rent_destination(
+        &mut self,
+        rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.rent_destination = rent_destination;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -396,6 +531,24 @@ impl<'a, 'b> CloseAddressLookupTableCpiBuilder<'a, 'b> {
         self.instruction.user_address_lookup_table = Some(user_address_lookup_table);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lut_registry(
+        &mut self,
+        lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lut_registry = lut_registry;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lut_counter(
+        &mut self,
+        lut_counter: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lut_counter = lut_counter;
+        self
+    }
     #[inline(always)]
     pub fn rent(&mut self, rent: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.rent = Some(rent);
@@ -440,6 +593,8 @@ impl<'a, 'b> CloseAddressLookupTableCpiBuilder<'a, 'b> {
 
             signer: self.instruction.signer.expect("signer is not set"),
 
+            rent_destination: self.instruction.rent_destination,
+
             system_program: self
                 .instruction
                 .system_program
@@ -460,6 +615,10 @@ impl<'a, 'b> CloseAddressLookupTableCpiBuilder<'a, 'b> {
                 .user_address_lookup_table
                 .expect("user_address_lookup_table is not set"),
 
+            lut_registry: self.instruction.lut_registry,
+
+            lut_counter: self.instruction.lut_counter,
+
             rent: self.instruction.rent.expect("rent is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -473,10 +632,13 @@ impl<'a, 'b> CloseAddressLookupTableCpiBuilder<'a, 'b> {
 struct CloseAddressLookupTableCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    rent_destination: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     address_lookup_table_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     address_lookup_table: Option<&'b solana_account_info::AccountInfo<'a>>,
     user_address_lookup_table: Option<&'b solana_account_info::AccountInfo<'a>>,
+    lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    lut_counter: Option<&'b solana_account_info::AccountInfo<'a>>,
     rent: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_pubkey::Pubkey;
 
 pub const CREATE_ADDRESS_LOOKUP_TABLE_DISCRIMINATOR: [u8; 8] = [90, 11, 161, 229, 55, 168, 159, 87];
 
@@ -15,12 +16,22 @@ pub const CREATE_ADDRESS_LOOKUP_TABLE_DISCRIMINATOR: [u8; 8] = [90, 11, 161, 229
 pub struct CreateAddressLookupTable {
     pub signer: solana_pubkey::Pubkey,
 
+    pub payer: Option<solana_pubkey::Pubkey>,
+
+    pub config: solana_pubkey::Pubkey,
+
+    pub treasury: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub address_lookup_table_program: solana_pubkey::Pubkey,
 
     pub address_lookup_table: solana_pubkey::Pubkey,
 
+    pub lut_registry: Option<solana_pubkey::Pubkey>,
+
+    pub lut_counter: solana_pubkey::Pubkey,
+
     pub user_address_lookup_table: solana_pubkey::Pubkey,
 
     pub rent: solana_pubkey::Pubkey,
@@ -40,8 +51,28 @@ impl CreateAddressLookupTable {
         args: CreateAddressLookupTableInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_instruction::AccountMeta::new(treasury, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -54,6 +85,18 @@ impl CreateAddressLookupTable {
             self.address_lookup_table,
             false,
         ));
+        if let Some(lut_registry) = self.lut_registry {
+            accounts.push(solana_instruction::AccountMeta::new(lut_registry, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            self.lut_counter,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(
             self.user_address_lookup_table,
             false,
@@ -105,6 +148,10 @@ impl Default for CreateAddressLookupTableInstructionData {
 pub struct CreateAddressLookupTableInstructionArgs {
     pub recent_slot: u64,
     pub id: u64,
+    pub initial_addresses: Option<Vec<Pubkey>>,
+    pub burst_fill: Option<bool>,
+    pub label: Option<String>,
+    pub metadata_uri: Option<String>,
 }
 
 impl CreateAddressLookupTableInstructionArgs {
@@ -118,21 +165,35 @@ impl CreateAddressLookupTableInstructionArgs {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   2. `[optional]` address_lookup_table_program (default to `AddressLookupTab1e1111111111111111111111111`)
-///   3. `[writable]` address_lookup_table
-///   4. `[writable]` user_address_lookup_table
-///   5. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   1. `[writable, signer, optional]` payer
+///   2. `[]` config
+///   3. `[writable, optional]` treasury
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` address_lookup_table_program (default to `AddressLookupTab1e1111111111111111111111111`)
+///   6. `[writable]` address_lookup_table
+///   7. `[writable, optional]` lut_registry
+///   8. `[writable]` lut_counter
+///   9. `[writable]` user_address_lookup_table
+///   10. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateAddressLookupTableBuilder {
     signer: Option<solana_pubkey::Pubkey>,
+    payer: Option<solana_pubkey::Pubkey>,
+    config: Option<solana_pubkey::Pubkey>,
+    treasury: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     address_lookup_table_program: Option<solana_pubkey::Pubkey>,
     address_lookup_table: Option<solana_pubkey::Pubkey>,
+    lut_registry: Option<solana_pubkey::Pubkey>,
+    lut_counter: Option<solana_pubkey::Pubkey>,
     user_address_lookup_table: Option<solana_pubkey::Pubkey>,
     rent: Option<solana_pubkey::Pubkey>,
     recent_slot: Option<u64>,
     id: Option<u64>,
+    initial_addresses: Option<Vec<Pubkey>>,
+    burst_fill: Option<bool>,
+    label: Option<String>,
+    metadata_uri: Option<String>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -145,6 +206,23 @@ impl CreateAddressLookupTableBuilder {
         self.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -168,6 +246,17 @@ impl CreateAddressLookupTableBuilder {
         self.address_lookup_table = Some(address_lookup_table);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lut_registry(&mut self, lut_registry: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.lut_registry = lut_registry;
+        self
+    }
+    #[inline(always)]
+    pub fn lut_counter(&mut self, lut_counter: solana_pubkey::Pubkey) -> &mut Self {
+        self.lut_counter = Some(lut_counter);
+        self
+    }
     #[inline(always)]
     pub fn user_address_lookup_table(
         &mut self,
@@ -192,6 +281,30 @@ impl CreateAddressLookupTableBuilder {
         self.id = Some(id);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn initial_addresses(&mut self, initial_addresses: Vec<Pubkey>) -> &mut Self {
+        self.initial_addresses = Some(initial_addresses);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn burst_fill(&mut self, burst_fill: bool) -> &mut Self {
+        self.burst_fill = Some(burst_fill);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn label(&mut self, label: String) -> &mut Self {
+        self.label = Some(label);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn metadata_uri(&mut self, metadata_uri: String) -> &mut Self {
+        self.metadata_uri = Some(metadata_uri);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -211,6 +324,9 @@ impl CreateAddressLookupTableBuilder {
     pub fn instruction(&self) -> solana_instruction::Instruction {
         let accounts = CreateAddressLookupTable {
             signer: self.signer.expect("signer is not set"),
+            payer: self.payer,
+            config: self.config.expect("config is not set"),
+            treasury: self.treasury,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -220,6 +336,8 @@ impl CreateAddressLookupTableBuilder {
             address_lookup_table: self
                 .address_lookup_table
                 .expect("address_lookup_table is not set"),
+            lut_registry: self.lut_registry,
+            lut_counter: self.lut_counter.expect("lut_counter is not set"),
             user_address_lookup_table: self
                 .user_address_lookup_table
                 .expect("user_address_lookup_table is not set"),
@@ -230,6 +348,10 @@ impl CreateAddressLookupTableBuilder {
         let args = CreateAddressLookupTableInstructionArgs {
             recent_slot: self.recent_slot.clone().expect("recent_slot is not set"),
             id: self.id.clone().expect("id is not set"),
+            initial_addresses: self.initial_addresses.clone(),
+            burst_fill: self.burst_fill.clone(),
+            label: self.label.clone(),
+            metadata_uri: self.metadata_uri.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -240,12 +362,22 @@ impl CreateAddressLookupTableBuilder {
 pub struct CreateAddressLookupTableCpiAccounts<'a, 'b> {
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table: &'b solana_account_info::AccountInfo<'a>,
 
+    pub lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub lut_counter: &'b solana_account_info::AccountInfo<'a>,
+
     pub user_address_lookup_table: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
@@ -258,12 +390,22 @@ pub struct CreateAddressLookupTableCpi<'a, 'b> {
 
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table: &'b solana_account_info::AccountInfo<'a>,
 
+    pub lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub lut_counter: &'b solana_account_info::AccountInfo<'a>,
+
     pub user_address_lookup_table: &'b solana_account_info::AccountInfo<'a>,
 
     pub rent: &'b solana_account_info::AccountInfo<'a>,
@@ -280,9 +422,14 @@ impl<'a, 'b> CreateAddressLookupTableCpi<'a, 'b> {
         Self {
             __program: program,
             signer: accounts.signer,
+            payer: accounts.payer,
+            config: accounts.config,
+            treasury: accounts.treasury,
             system_program: accounts.system_program,
             address_lookup_table_program: accounts.address_lookup_table_program,
             address_lookup_table: accounts.address_lookup_table,
+            lut_registry: accounts.lut_registry,
+            lut_counter: accounts.lut_counter,
             user_address_lookup_table: accounts.user_address_lookup_table,
             rent: accounts.rent,
             __args: args,
@@ -311,8 +458,28 @@ impl<'a, 'b> CreateAddressLookupTableCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.signer.key, true));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(*payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_instruction::AccountMeta::new(*treasury.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -325,6 +492,21 @@ impl<'a, 'b> CreateAddressLookupTableCpi<'a, 'b> {
             *self.address_lookup_table.key,
             false,
         ));
+        if let Some(lut_registry) = self.lut_registry {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *lut_registry.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new(
+            *self.lut_counter.key,
+            false,
+        ));
         accounts.push(solana_instruction::AccountMeta::new(
             *self.user_address_lookup_table.key,
             false,
@@ -351,12 +533,23 @@ impl<'a, 'b> CreateAddressLookupTableCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.signer.clone());
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        account_infos.push(self.config.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.address_lookup_table_program.clone());
         account_infos.push(self.address_lookup_table.clone());
+        if let Some(lut_registry) = self.lut_registry {
+            account_infos.push(lut_registry.clone());
+        }
+        account_infos.push(self.lut_counter.clone());
         account_infos.push(self.user_address_lookup_table.clone());
         account_infos.push(self.rent.clone());
         remaining_accounts
@@ -376,11 +569,16 @@ impl<'a, 'b> CreateAddressLookupTableCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[]` system_program
-///   2. `[]` address_lookup_table_program
-///   3. `[writable]` address_lookup_table
-///   4. `[writable]` user_address_lookup_table
-///   5. `[]` rent
+///   1. `[writable, signer, optional]` payer
+///   2. `[]` config
+///   3. `[writable, optional]` treasury
+///   4. `[]` system_program
+///   5. `[]` address_lookup_table_program
+///   6. `[writable]` address_lookup_table
+///   7. `[writable, optional]` lut_registry
+///   8. `[writable]` lut_counter
+///   9. `[writable]` user_address_lookup_table
+///   10. `[]` rent
 #[derive(Clone, Debug)]
 pub struct CreateAddressLookupTableCpiBuilder<'a, 'b> {
     instruction: Box<CreateAddressLookupTableCpiBuilderInstruction<'a, 'b>>,
@@ -391,13 +589,22 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
         let instruction = Box::new(CreateAddressLookupTableCpiBuilderInstruction {
             __program: program,
             signer: None,
+            payer: None,
+            config: None,
+            treasury: None,
             system_program: None,
             address_lookup_table_program: None,
             address_lookup_table: None,
+            lut_registry: None,
+            lut_counter: None,
             user_address_lookup_table: None,
             rent: None,
             recent_slot: None,
             id: None,
+            initial_addresses: None,
+            burst_fill: None,
+            label: None,
+            metadata_uri: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -407,6 +614,26 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
         self.instruction.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -431,6 +658,23 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
         self.instruction.address_lookup_table = Some(address_lookup_table);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn lut_registry(
+        &mut self,
+        lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lut_registry = lut_registry;
+        self
+    }
+    #[inline(always)]
+    pub fn lut_counter(
+        &mut self,
+        lut_counter: &'b solana_account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.lut_counter = Some(lut_counter);
+        self
+    }
     #[inline(always)]
     pub fn user_address_lookup_table(
         &mut self,
@@ -454,6 +698,30 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
         self.instruction.id = Some(id);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn initial_addresses(&mut self, initial_addresses: Vec<Pubkey>) -> &mut Self {
+        self.instruction.initial_addresses = Some(initial_addresses);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn burst_fill(&mut self, burst_fill: bool) -> &mut Self {
+        self.instruction.burst_fill = Some(burst_fill);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn label(&mut self, label: String) -> &mut Self {
+        self.instruction.label = Some(label);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn metadata_uri(&mut self, metadata_uri: String) -> &mut Self {
+        self.instruction.metadata_uri = Some(metadata_uri);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -495,12 +763,22 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
                 .clone()
                 .expect("recent_slot is not set"),
             id: self.instruction.id.clone().expect("id is not set"),
+            initial_addresses: self.instruction.initial_addresses.clone(),
+            burst_fill: self.instruction.burst_fill.clone(),
+            label: self.instruction.label.clone(),
+            metadata_uri: self.instruction.metadata_uri.clone(),
         };
         let instruction = CreateAddressLookupTableCpi {
             __program: self.instruction.__program,
 
             signer: self.instruction.signer.expect("signer is not set"),
 
+            payer: self.instruction.payer,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            treasury: self.instruction.treasury,
+
             system_program: self
                 .instruction
                 .system_program
@@ -516,6 +794,13 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
                 .address_lookup_table
                 .expect("address_lookup_table is not set"),
 
+            lut_registry: self.instruction.lut_registry,
+
+            lut_counter: self
+                .instruction
+                .lut_counter
+                .expect("lut_counter is not set"),
+
             user_address_lookup_table: self
                 .instruction
                 .user_address_lookup_table
@@ -535,13 +820,22 @@ impl<'a, 'b> CreateAddressLookupTableCpiBuilder<'a, 'b> {
 struct CreateAddressLookupTableCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     address_lookup_table_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     address_lookup_table: Option<&'b solana_account_info::AccountInfo<'a>>,
+    lut_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
+    lut_counter: Option<&'b solana_account_info::AccountInfo<'a>>,
     user_address_lookup_table: Option<&'b solana_account_info::AccountInfo<'a>>,
     rent: Option<&'b solana_account_info::AccountInfo<'a>>,
     recent_slot: Option<u64>,
     id: Option<u64>,
+    initial_addresses: Option<Vec<Pubkey>>,
+    burst_fill: Option<bool>,
+    label: Option<String>,
+    metadata_uri: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -16,6 +16,12 @@ pub const EXTEND_ADDRESS_LOOKUP_TABLE_DISCRIMINATOR: [u8; 8] =
 pub struct ExtendAddressLookupTable {
     pub signer: solana_pubkey::Pubkey,
 
+    pub payer: Option<solana_pubkey::Pubkey>,
+
+    pub config: solana_pubkey::Pubkey,
+
+    pub treasury: Option<solana_pubkey::Pubkey>,
+
     pub system_program: solana_pubkey::Pubkey,
 
     pub address_lookup_table_program: solana_pubkey::Pubkey,
@@ -37,8 +43,28 @@ impl ExtendAddressLookupTable {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.signer, true));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.config,
+            false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_instruction::AccountMeta::new(treasury, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -100,14 +126,20 @@ impl Default for ExtendAddressLookupTableInstructionData {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   2. `[optional]` address_lookup_table_program (default to `AddressLookupTab1e1111111111111111111111111`)
-///   3. `[writable]` address_lookup_table
-///   4. `[writable]` user_address_lookup_table
-///   5. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   1. `[writable, signer, optional]` payer
+///   2. `[]` config
+///   3. `[writable, optional]` treasury
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` address_lookup_table_program (default to `AddressLookupTab1e1111111111111111111111111`)
+///   6. `[writable]` address_lookup_table
+///   7. `[writable]` user_address_lookup_table
+///   8. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ExtendAddressLookupTableBuilder {
     signer: Option<solana_pubkey::Pubkey>,
+    payer: Option<solana_pubkey::Pubkey>,
+    config: Option<solana_pubkey::Pubkey>,
+    treasury: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
     address_lookup_table_program: Option<solana_pubkey::Pubkey>,
     address_lookup_table: Option<solana_pubkey::Pubkey>,
@@ -125,6 +157,23 @@ impl ExtendAddressLookupTableBuilder {
         self.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     #[inline(always)]
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
@@ -181,6 +230,9 @@ impl ExtendAddressLookupTableBuilder {
     pub fn instruction(&self) -> solana_instruction::Instruction {
         let accounts = ExtendAddressLookupTable {
             signer: self.signer.expect("signer is not set"),
+            payer: self.payer,
+            config: self.config.expect("config is not set"),
+            treasury: self.treasury,
             system_program: self
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -206,6 +258,12 @@ impl ExtendAddressLookupTableBuilder {
 pub struct ExtendAddressLookupTableCpiAccounts<'a, 'b> {
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table_program: &'b solana_account_info::AccountInfo<'a>,
@@ -224,6 +282,12 @@ pub struct ExtendAddressLookupTableCpi<'a, 'b> {
 
     pub signer: &'b solana_account_info::AccountInfo<'a>,
 
+    pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub config: &'b solana_account_info::AccountInfo<'a>,
+
+    pub treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
 
     pub address_lookup_table_program: &'b solana_account_info::AccountInfo<'a>,
@@ -243,6 +307,9 @@ impl<'a, 'b> ExtendAddressLookupTableCpi<'a, 'b> {
         Self {
             __program: program,
             signer: accounts.signer,
+            payer: accounts.payer,
+            config: accounts.config,
+            treasury: accounts.treasury,
             system_program: accounts.system_program,
             address_lookup_table_program: accounts.address_lookup_table_program,
             address_lookup_table: accounts.address_lookup_table,
@@ -273,8 +340,28 @@ impl<'a, 'b> ExtendAddressLookupTableCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(*self.signer.key, true));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_instruction::AccountMeta::new(*payer.key, true));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false,
+        ));
+        if let Some(treasury) = self.treasury {
+            accounts.push(solana_instruction::AccountMeta::new(*treasury.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::LUTS_ID,
+                false,
+            ));
+        }
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
@@ -311,9 +398,16 @@ impl<'a, 'b> ExtendAddressLookupTableCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.signer.clone());
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        account_infos.push(self.config.clone());
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.address_lookup_table_program.clone());
         account_infos.push(self.address_lookup_table.clone());
@@ -336,11 +430,14 @@ impl<'a, 'b> ExtendAddressLookupTableCpi<'a, 'b> {
 /// ### Accounts:
 ///
 ///   0. `[writable, signer]` signer
-///   1. `[]` system_program
-///   2. `[]` address_lookup_table_program
-///   3. `[writable]` address_lookup_table
-///   4. `[writable]` user_address_lookup_table
-///   5. `[]` rent
+///   1. `[writable, signer, optional]` payer
+///   2. `[]` config
+///   3. `[writable, optional]` treasury
+///   4. `[]` system_program
+///   5. `[]` address_lookup_table_program
+///   6. `[writable]` address_lookup_table
+///   7. `[writable]` user_address_lookup_table
+///   8. `[]` rent
 #[derive(Clone, Debug)]
 pub struct ExtendAddressLookupTableCpiBuilder<'a, 'b> {
     instruction: Box<ExtendAddressLookupTableCpiBuilderInstruction<'a, 'b>>,
@@ -351,6 +448,9 @@ impl<'a, 'b> ExtendAddressLookupTableCpiBuilder<'a, 'b> {
         let instruction = Box::new(ExtendAddressLookupTableCpiBuilderInstruction {
             __program: program,
             signer: None,
+            payer: None,
+            config: None,
+            treasury: None,
             system_program: None,
             address_lookup_table_program: None,
             address_lookup_table: None,
@@ -365,6 +465,26 @@ impl<'a, 'b> ExtendAddressLookupTableCpiBuilder<'a, 'b> {
         self.instruction.signer = Some(signer);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    #[inline(always)]
+    pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.config = Some(config);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -441,6 +561,12 @@ impl<'a, 'b> ExtendAddressLookupTableCpiBuilder<'a, 'b> {
 
             signer: self.instruction.signer.expect("signer is not set"),
 
+            payer: self.instruction.payer,
+
+            config: self.instruction.config.expect("config is not set"),
+
+            treasury: self.instruction.treasury,
+
             system_program: self
                 .instruction
                 .system_program
@@ -474,6 +600,9 @@ impl<'a, 'b> ExtendAddressLookupTableCpiBuilder<'a, 'b> {
 struct ExtendAddressLookupTableCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_account_info::AccountInfo<'a>,
     signer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+    config: Option<&'b solana_account_info::AccountInfo<'a>>,
+    treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     address_lookup_table_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     address_lookup_table: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -5,12 +5,64 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub mod r#accept_authority;
+pub mod r#add_lut_set_member;
 pub mod r#close_address_lookup_table;
+pub mod r#close_orphaned_wrapper;
+pub mod r#close_previous_address_lookup_table;
 pub mod r#create_address_lookup_table;
+pub mod r#create_address_lookup_table_with_namespace;
+pub mod r#create_lut_set;
 pub mod r#deactivate_address_lookup_table;
 pub mod r#extend_address_lookup_table;
+pub mod r#extend_address_lookup_table_with_args;
+pub mod r#extend_address_lookup_table_with_mode;
+pub mod r#extend_lut_set;
+pub mod r#freeze_address_lookup_table;
+pub mod r#get_lut_status;
+pub mod r#grant_delegate;
+pub mod r#initialize_config;
+pub mod r#initialize_lut_registry;
+pub mod r#mark_in_use;
+pub mod r#migrate_wrapper;
+pub mod r#propose_authority;
+pub mod r#remove_lut_set_member;
+pub mod r#resolve_address_indexes;
+pub mod r#revoke_delegate;
+pub mod r#rotate_address_lookup_table;
+pub mod r#set_owner_allowlist;
+pub mod r#set_paused;
+pub mod r#set_wrapper_metadata;
+pub mod r#sync_address_lookup_table;
+pub mod r#update_config;
 
+pub use self::r#accept_authority::*;
+pub use self::r#add_lut_set_member::*;
 pub use self::r#close_address_lookup_table::*;
+pub use self::r#close_orphaned_wrapper::*;
+pub use self::r#close_previous_address_lookup_table::*;
 pub use self::r#create_address_lookup_table::*;
+pub use self::r#create_address_lookup_table_with_namespace::*;
+pub use self::r#create_lut_set::*;
 pub use self::r#deactivate_address_lookup_table::*;
 pub use self::r#extend_address_lookup_table::*;
+pub use self::r#extend_address_lookup_table_with_args::*;
+pub use self::r#extend_address_lookup_table_with_mode::*;
+pub use self::r#extend_lut_set::*;
+pub use self::r#freeze_address_lookup_table::*;
+pub use self::r#get_lut_status::*;
+pub use self::r#grant_delegate::*;
+pub use self::r#initialize_config::*;
+pub use self::r#initialize_lut_registry::*;
+pub use self::r#mark_in_use::*;
+pub use self::r#migrate_wrapper::*;
+pub use self::r#propose_authority::*;
+pub use self::r#remove_lut_set_member::*;
+pub use self::r#resolve_address_indexes::*;
+pub use self::r#revoke_delegate::*;
+pub use self::r#rotate_address_lookup_table::*;
+pub use self::r#set_owner_allowlist::*;
+pub use self::r#set_paused::*;
+pub use self::r#set_wrapper_metadata::*;
+pub use self::r#sync_address_lookup_table::*;
+pub use self::r#update_config::*;
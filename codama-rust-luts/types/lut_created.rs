@@ -28,4 +28,8 @@ pub struct LutCreated {
     )]
     pub authority: Pubkey,
     pub slot: u64,
+    pub initial_addresses: u32,
+    pub fee_lamports: u64,
+    pub label: String,
+    pub metadata_uri: String,
 }
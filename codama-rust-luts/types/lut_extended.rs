@@ -19,4 +19,7 @@ pub struct LutExtended {
     pub wrapper: Pubkey,
     pub addresses_added: u32,
     pub total_addresses: u32,
+    pub duplicates_dropped: u32,
+    pub fee_lamports: u64,
+    pub addresses_rejected: u32,
 }
@@ -5,12 +5,64 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub mod r#config_updated;
+pub mod r#extend_mode;
+pub mod r#extend_result;
+pub mod r#lut_authority_accepted;
+pub mod r#lut_authority_proposed;
 pub mod r#lut_closed;
 pub mod r#lut_created;
 pub mod r#lut_deactivated;
+pub mod r#lut_delegate;
+pub mod r#lut_delegate_granted;
+pub mod r#lut_delegate_revoked;
 pub mod r#lut_extended;
+pub mod r#lut_frozen;
+pub mod r#lut_marked_in_use;
+pub mod r#lut_metadata_set;
+pub mod r#lut_registry_initialized;
+pub mod r#lut_rotated;
+pub mod r#lut_set_created;
+pub mod r#lut_set_extended;
+pub mod r#lut_set_member_added;
+pub mod r#lut_set_member_removed;
+pub mod r#lut_set_spill;
+pub mod r#lut_status;
+pub mod r#lut_status_info;
+pub mod r#lut_synced;
+pub mod r#luts_paused;
+pub mod r#luts_unpaused;
+pub mod r#orphaned_wrapper_closed;
+pub mod r#owner_allowlist_set;
+pub mod r#wrapper_migrated;
 
+pub use self::r#config_updated::*;
+pub use self::r#extend_mode::*;
+pub use self::r#extend_result::*;
+pub use self::r#lut_authority_accepted::*;
+pub use self::r#lut_authority_proposed::*;
 pub use self::r#lut_closed::*;
 pub use self::r#lut_created::*;
 pub use self::r#lut_deactivated::*;
+pub use self::r#lut_delegate::*;
+pub use self::r#lut_delegate_granted::*;
+pub use self::r#lut_delegate_revoked::*;
 pub use self::r#lut_extended::*;
+pub use self::r#lut_frozen::*;
+pub use self::r#lut_marked_in_use::*;
+pub use self::r#lut_metadata_set::*;
+pub use self::r#lut_registry_initialized::*;
+pub use self::r#lut_rotated::*;
+pub use self::r#lut_set_created::*;
+pub use self::r#lut_set_extended::*;
+pub use self::r#lut_set_member_added::*;
+pub use self::r#lut_set_member_removed::*;
+pub use self::r#lut_set_spill::*;
+pub use self::r#lut_status::*;
+pub use self::r#lut_status_info::*;
+pub use self::r#lut_synced::*;
+pub use self::r#luts_paused::*;
+pub use self::r#luts_unpaused::*;
+pub use self::r#orphaned_wrapper_closed::*;
+pub use self::r#owner_allowlist_set::*;
+pub use self::r#wrapper_migrated::*;
@@ -1,3 +1,10 @@
 export const USER_ADDRESS_LOOKUP_TABLE_SEED = "UserAddressLookupTable";
 export const ADDRESS_LOOKUP_TABLE_PROGRAM_ID =
   "AddressLookupTab1e1111111111111111111111111";
+export const CONFIG_SEED = "Config";
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID =
+  "BPFLoaderUpgradeab1e11111111111111111111111";
+export const LUT_SET_SEED = "LutSet";
+export const LUT_REGISTRY_SEED = "LutRegistry";
+export const LUT_COUNTER_SEED = "LutCounter";
+export const ADDRESS_NOT_FOUND = 0xffff;
@@ -0,0 +1,21 @@
+import { PublicKey } from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import {
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+  CONFIG_SEED,
+} from "../constants";
+import { LUTS_PROGRAM_ADDRESS } from "../../codama-ts-luts";
+
+export function getConfigAddress(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(anchor.utils.bytes.utf8.encode(CONFIG_SEED))],
+    new PublicKey(LUTS_PROGRAM_ADDRESS)
+  );
+}
+
+export function getProgramDataAddress(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [new PublicKey(LUTS_PROGRAM_ADDRESS).toBuffer()],
+    new PublicKey(BPF_LOADER_UPGRADEABLE_PROGRAM_ID)
+  );
+}
@@ -1,2 +1,6 @@
 export * from "./userAddressLookupTable";
 export * from "./addressLookupTable";
+export * from "./config";
+export * from "./lutSet";
+export * from "./lutRegistry";
+export * from "./lutCounter";
@@ -0,0 +1,14 @@
+import { PublicKey } from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import { LUT_REGISTRY_SEED } from "../constants";
+import { LUTS_PROGRAM_ADDRESS } from "../../codama-ts-luts";
+
+export function getLutRegistryAddress(signer: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(anchor.utils.bytes.utf8.encode(LUT_REGISTRY_SEED)),
+      signer.toBuffer(),
+    ],
+    new PublicKey(LUTS_PROGRAM_ADDRESS)
+  );
+}
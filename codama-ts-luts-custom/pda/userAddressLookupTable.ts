@@ -19,3 +19,17 @@ export function getUserAddressLookupTableAddress(
     new PublicKey(LUTS_PROGRAM_ADDRESS)
   );
 }
+
+export function getNamespacedUserAddressLookupTableAddress(
+  signer: PublicKey,
+  namespace: Uint8Array
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(anchor.utils.bytes.utf8.encode(USER_ADDRESS_LOOKUP_TABLE_SEED)),
+      signer.toBuffer(),
+      Buffer.from(namespace),
+    ],
+    new PublicKey(LUTS_PROGRAM_ADDRESS)
+  );
+}
@@ -0,0 +1,23 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getAcceptAuthorityInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildAcceptAuthorityInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildAcceptAuthorityInstruction({
+  signer,
+  userAddressLookupTable,
+}: BuildAcceptAuthorityInput): TransactionInstruction {
+  const ix = getAcceptAuthorityInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getAddLutSetMemberInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildAddLutSetMemberInput = {
+  signer: PublicKey;
+  lutSet: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildAddLutSetMemberInstruction({
+  signer,
+  lutSet,
+  userAddressLookupTable,
+}: BuildAddLutSetMemberInput): TransactionInstruction {
+  const ix = getAddLutSetMemberInstruction({
+    signer: toTransactionSigner(signer),
+    lutSet: address(lutSet.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
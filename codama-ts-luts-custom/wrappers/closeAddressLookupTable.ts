@@ -7,17 +7,28 @@ export type BuildCloseAddressLookupTableInput = {
   signer: PublicKey;
   addressLookupTable: PublicKey;
   userAddressLookupTable: PublicKey;
+  rentDestination?: PublicKey;
+  lutRegistry?: PublicKey;
+  lutCounter?: PublicKey;
 };
 
 export function buildCloseAddressLookupTableInstruction({
   signer,
   addressLookupTable,
   userAddressLookupTable,
+  rentDestination,
+  lutRegistry,
+  lutCounter,
 }: BuildCloseAddressLookupTableInput): TransactionInstruction {
   const ix = getCloseAddressLookupTableInstruction({
     signer: toTransactionSigner(signer),
     addressLookupTable: address(addressLookupTable.toBase58()),
     userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    rentDestination: rentDestination
+      ? address(rentDestination.toBase58())
+      : undefined,
+    lutRegistry: lutRegistry ? address(lutRegistry.toBase58()) : undefined,
+    lutCounter: lutCounter ? address(lutCounter.toBase58()) : undefined,
   });
 
   return toTransactionInstruction(
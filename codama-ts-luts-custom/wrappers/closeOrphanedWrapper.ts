@@ -0,0 +1,32 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCloseOrphanedWrapperInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildCloseOrphanedWrapperInput = {
+  signer: PublicKey;
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+  lutRegistry?: PublicKey;
+  lutCounter?: PublicKey;
+};
+
+export function buildCloseOrphanedWrapperInstruction({
+  signer,
+  addressLookupTable,
+  userAddressLookupTable,
+  lutRegistry,
+  lutCounter,
+}: BuildCloseOrphanedWrapperInput): TransactionInstruction {
+  const ix = getCloseOrphanedWrapperInstruction({
+    signer: toTransactionSigner(signer),
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    lutRegistry: lutRegistry ? address(lutRegistry.toBase58()) : undefined,
+    lutCounter: lutCounter ? address(lutCounter.toBase58()) : undefined,
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,31 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getClosePreviousAddressLookupTableInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildClosePreviousAddressLookupTableInput = {
+  signer: PublicKey;
+  previousAddressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+  rentDestination?: PublicKey;
+};
+
+export function buildClosePreviousAddressLookupTableInstruction({
+  signer,
+  previousAddressLookupTable,
+  userAddressLookupTable,
+  rentDestination,
+}: BuildClosePreviousAddressLookupTableInput): TransactionInstruction {
+  const ix = getClosePreviousAddressLookupTableInstruction({
+    signer: toTransactionSigner(signer),
+    previousAddressLookupTable: address(previousAddressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    rentDestination: rentDestination
+      ? address(rentDestination.toBase58())
+      : undefined,
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -4,6 +4,8 @@ import {
   getCreateAddressLookupTableInstruction,
 } from "../../codama-ts-luts";
 import {
+  getConfigAddress,
+  getLutCounterAddress,
   getUserAddressLookupTableAddress,
   deriveAddressLookupTableAddress,
 } from "../pda";
@@ -12,8 +14,15 @@ import { address } from "@solana/kit";
 
 export type BuildCreateAddressLookupTableInput = {
   signer: PublicKey;
+  payer?: PublicKey;
+  treasury?: PublicKey;
+  lutRegistry?: PublicKey;
   recentSlot: bigint | number;
   id: bigint | number;
+  initialAddresses?: PublicKey[];
+  burstFill?: boolean;
+  label?: string;
+  metadataUri?: string;
 };
 
 export type BuildCreateAddressLookupTableOutput = {
@@ -24,21 +33,41 @@ export type BuildCreateAddressLookupTableOutput = {
 
 export function buildCreateAddressLookupTableInstruction({
   signer,
+  payer,
+  treasury,
+  lutRegistry,
   recentSlot,
   id,
+  initialAddresses,
+  burstFill,
+  label,
+  metadataUri,
 }: BuildCreateAddressLookupTableInput): BuildCreateAddressLookupTableOutput {
   const [userAddressLookupTable] = getUserAddressLookupTableAddress(signer, id);
   const [addressLookupTable] = deriveAddressLookupTableAddress(
     userAddressLookupTable,
     recentSlot
   );
+  const [config] = getConfigAddress();
+  const [lutCounter] = getLutCounterAddress(signer);
 
   const input: CreateAddressLookupTableInput = {
     signer: toTransactionSigner(signer),
+    payer: payer ? toTransactionSigner(payer) : undefined,
+    config: address(config.toBase58()),
+    treasury: treasury ? address(treasury.toBase58()) : undefined,
     addressLookupTable: address(addressLookupTable.toBase58()),
+    lutRegistry: lutRegistry ? address(lutRegistry.toBase58()) : undefined,
+    lutCounter: address(lutCounter.toBase58()),
     userAddressLookupTable: address(userAddressLookupTable.toBase58()),
     recentSlot: BigInt(recentSlot),
     id: BigInt(id),
+    initialAddresses: initialAddresses
+      ? initialAddresses.map((a) => address(a.toBase58()))
+      : null,
+    burstFill: burstFill ?? null,
+    label: label ?? null,
+    metadataUri: metadataUri ?? null,
   };
 
   const ix = getCreateAddressLookupTableInstruction(input);
@@ -0,0 +1,82 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  CreateAddressLookupTableWithNamespaceInput,
+  getCreateAddressLookupTableWithNamespaceInstruction,
+} from "../../codama-ts-luts";
+import {
+  getConfigAddress,
+  getLutCounterAddress,
+  getNamespacedUserAddressLookupTableAddress,
+  deriveAddressLookupTableAddress,
+} from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildCreateAddressLookupTableWithNamespaceInput = {
+  signer: PublicKey;
+  payer?: PublicKey;
+  treasury?: PublicKey;
+  recentSlot: bigint | number;
+  namespace: Uint8Array;
+  initialAddresses?: PublicKey[];
+  burstFill?: boolean;
+  label?: string;
+  metadataUri?: string;
+};
+
+export type BuildCreateAddressLookupTableWithNamespaceOutput = {
+  instruction: TransactionInstruction;
+  userAddressLookupTable: PublicKey;
+  addressLookupTable: PublicKey;
+};
+
+export function buildCreateAddressLookupTableWithNamespaceInstruction({
+  signer,
+  payer,
+  treasury,
+  recentSlot,
+  namespace,
+  initialAddresses,
+  burstFill,
+  label,
+  metadataUri,
+}: BuildCreateAddressLookupTableWithNamespaceInput): BuildCreateAddressLookupTableWithNamespaceOutput {
+  const [userAddressLookupTable] = getNamespacedUserAddressLookupTableAddress(
+    signer,
+    namespace
+  );
+  const [addressLookupTable] = deriveAddressLookupTableAddress(
+    userAddressLookupTable,
+    recentSlot
+  );
+  const [config] = getConfigAddress();
+  const [lutCounter] = getLutCounterAddress(signer);
+
+  const input: CreateAddressLookupTableWithNamespaceInput = {
+    signer: toTransactionSigner(signer),
+    payer: payer ? toTransactionSigner(payer) : undefined,
+    config: address(config.toBase58()),
+    treasury: treasury ? address(treasury.toBase58()) : undefined,
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    lutCounter: address(lutCounter.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    recentSlot: BigInt(recentSlot),
+    namespace: Array.from(namespace),
+    initialAddresses: initialAddresses
+      ? initialAddresses.map((a) => address(a.toBase58()))
+      : null,
+    burstFill: burstFill ?? null,
+    label: label ?? null,
+    metadataUri: metadataUri ?? null,
+  };
+
+  const ix = getCreateAddressLookupTableWithNamespaceInstruction(input);
+
+  return {
+    instruction: toTransactionInstruction(
+      ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+    ),
+    userAddressLookupTable,
+    addressLookupTable,
+  };
+}
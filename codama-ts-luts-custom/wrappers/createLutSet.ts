@@ -0,0 +1,35 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getCreateLutSetInstruction } from "../../codama-ts-luts";
+import { getLutSetAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildCreateLutSetInput = {
+  signer: PublicKey;
+  id: bigint | number;
+};
+
+export type BuildCreateLutSetOutput = {
+  instruction: TransactionInstruction;
+  lutSet: PublicKey;
+};
+
+export function buildCreateLutSetInstruction({
+  signer,
+  id,
+}: BuildCreateLutSetInput): BuildCreateLutSetOutput {
+  const [lutSet] = getLutSetAddress(signer, id);
+
+  const ix = getCreateLutSetInstruction({
+    signer: toTransactionSigner(signer),
+    lutSet: address(lutSet.toBase58()),
+    id: BigInt(id),
+  });
+
+  return {
+    instruction: toTransactionInstruction(
+      ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+    ),
+    lutSet,
+  };
+}
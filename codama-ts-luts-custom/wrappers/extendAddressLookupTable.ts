@@ -1,10 +1,13 @@
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { getExtendAddressLookupTableInstruction } from "../../codama-ts-luts";
+import { getConfigAddress } from "../pda";
 import { toTransactionSigner } from "../utils";
 import { address, AccountRole } from "@solana/kit";
 
 export type BuildExtendAddressLookupTableInput = {
   signer: PublicKey;
+  payer?: PublicKey;
+  treasury?: PublicKey;
   addressLookupTable: PublicKey;
   userAddressLookupTable: PublicKey;
   newAddresses: PublicKey[];
@@ -12,12 +15,19 @@ export type BuildExtendAddressLookupTableInput = {
 
 export function buildExtendAddressLookupTableInstruction({
   signer,
+  payer,
+  treasury,
   addressLookupTable,
   userAddressLookupTable,
   newAddresses,
 }: BuildExtendAddressLookupTableInput): TransactionInstruction {
+  const [config] = getConfigAddress();
+
   const ix = getExtendAddressLookupTableInstruction({
     signer: toTransactionSigner(signer),
+    payer: payer ? toTransactionSigner(payer) : undefined,
+    config: address(config.toBase58()),
+    treasury: treasury ? address(treasury.toBase58()) : undefined,
     addressLookupTable: address(addressLookupTable.toBase58()),
     userAddressLookupTable: address(userAddressLookupTable.toBase58()),
   });
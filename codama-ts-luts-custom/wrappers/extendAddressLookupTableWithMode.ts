@@ -0,0 +1,45 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import {
+  ExtendMode,
+  getExtendAddressLookupTableWithModeInstruction,
+} from "../../codama-ts-luts";
+import { getConfigAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildExtendAddressLookupTableWithModeInput = {
+  signer: PublicKey;
+  payer?: PublicKey;
+  treasury?: PublicKey;
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+  newAddresses: PublicKey[];
+  mode: ExtendMode;
+};
+
+export function buildExtendAddressLookupTableWithModeInstruction({
+  signer,
+  payer,
+  treasury,
+  addressLookupTable,
+  userAddressLookupTable,
+  newAddresses,
+  mode,
+}: BuildExtendAddressLookupTableWithModeInput): TransactionInstruction {
+  const [config] = getConfigAddress();
+
+  const ix = getExtendAddressLookupTableWithModeInstruction({
+    signer: toTransactionSigner(signer),
+    payer: payer ? toTransactionSigner(payer) : undefined,
+    config: address(config.toBase58()),
+    treasury: treasury ? address(treasury.toBase58()) : undefined,
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    addresses: newAddresses.map((addr) => address(addr.toBase58())),
+    mode,
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
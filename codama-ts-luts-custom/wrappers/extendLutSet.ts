@@ -0,0 +1,109 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getExtendLutSetInstruction } from "../../codama-ts-luts";
+import {
+  getConfigAddress,
+  getLutCounterAddress,
+  getUserAddressLookupTableAddress,
+  deriveAddressLookupTableAddress,
+} from "../pda";
+import { toTransactionSigner } from "../utils";
+import { address, AccountRole } from "@solana/kit";
+
+export type LutSetMember = {
+  userAddressLookupTable: PublicKey;
+  addressLookupTable: PublicKey;
+};
+
+export type BuildExtendLutSetInput = {
+  signer: PublicKey;
+  payer?: PublicKey;
+  treasury?: PublicKey;
+  lutSet: PublicKey;
+  members: LutSetMember[];
+  newAddresses: PublicKey[];
+  spill?: { id: bigint | number; recentSlot: bigint | number };
+};
+
+export type BuildExtendLutSetOutput = {
+  instruction: TransactionInstruction;
+  newMember?: LutSetMember;
+};
+
+export function buildExtendLutSetInstruction({
+  signer,
+  payer,
+  treasury,
+  lutSet,
+  members,
+  newAddresses,
+  spill,
+}: BuildExtendLutSetInput): BuildExtendLutSetOutput {
+  const [config] = getConfigAddress();
+  let newMember: LutSetMember | undefined;
+  if (spill) {
+    const [userAddressLookupTable] = getUserAddressLookupTableAddress(
+      signer,
+      spill.id
+    );
+    const [addressLookupTable] = deriveAddressLookupTableAddress(
+      userAddressLookupTable,
+      spill.recentSlot
+    );
+    newMember = { userAddressLookupTable, addressLookupTable };
+  }
+
+  const ix = getExtendLutSetInstruction({
+    signer: toTransactionSigner(signer),
+    payer: payer ? toTransactionSigner(payer) : undefined,
+    config: address(config.toBase58()),
+    treasury: treasury ? address(treasury.toBase58()) : undefined,
+    lutSet: address(lutSet.toBase58()),
+    newUserAddressLookupTable: newMember
+      ? address(newMember.userAddressLookupTable.toBase58())
+      : undefined,
+    newAddressLookupTable: newMember
+      ? address(newMember.addressLookupTable.toBase58())
+      : undefined,
+    lutCounter: spill
+      ? address(getLutCounterAddress(signer)[0].toBase58())
+      : undefined,
+    addresses: newAddresses.map((addr) => address(addr.toBase58())),
+    spill: spill
+      ? { id: BigInt(spill.id), recentSlot: BigInt(spill.recentSlot) }
+      : null,
+  });
+
+  const keys = ix.accounts.map((acc) => ({
+    pubkey: new PublicKey(acc.address),
+    isSigner:
+      acc.role === AccountRole.READONLY_SIGNER ||
+      acc.role === AccountRole.WRITABLE_SIGNER,
+    isWritable:
+      acc.role === AccountRole.WRITABLE ||
+      acc.role === AccountRole.WRITABLE_SIGNER,
+  }));
+
+  for (const member of members) {
+    keys.push(
+      {
+        pubkey: member.userAddressLookupTable,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: member.addressLookupTable,
+        isSigner: false,
+        isWritable: true,
+      }
+    );
+  }
+
+  return {
+    instruction: new TransactionInstruction({
+      programId: new PublicKey(ix.programAddress),
+      keys,
+      data: Buffer.from(ix.data),
+    }),
+    newMember,
+  };
+}
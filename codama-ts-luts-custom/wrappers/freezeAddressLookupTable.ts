@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getFreezeAddressLookupTableInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildFreezeAddressLookupTableInput = {
+  signer: PublicKey;
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildFreezeAddressLookupTableInstruction({
+  signer,
+  addressLookupTable,
+  userAddressLookupTable,
+}: BuildFreezeAddressLookupTableInput): TransactionInstruction {
+  const ix = getFreezeAddressLookupTableInstruction({
+    signer: toTransactionSigner(signer),
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,23 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getGetLutStatusInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildGetLutStatusInput = {
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildGetLutStatusInstruction({
+  addressLookupTable,
+  userAddressLookupTable,
+}: BuildGetLutStatusInput): TransactionInstruction {
+  const ix = getGetLutStatusInstruction({
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
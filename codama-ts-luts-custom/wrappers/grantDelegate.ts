@@ -0,0 +1,29 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getGrantDelegateInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildGrantDelegateInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+  delegate: PublicKey;
+  expiresAtSlot?: bigint | number;
+};
+
+export function buildGrantDelegateInstruction({
+  signer,
+  userAddressLookupTable,
+  delegate,
+  expiresAtSlot,
+}: BuildGrantDelegateInput): TransactionInstruction {
+  const ix = getGrantDelegateInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    delegate: address(delegate.toBase58()),
+    expiresAtSlot: expiresAtSlot === undefined ? null : BigInt(expiresAtSlot),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -1,4 +1,30 @@
 export * from "./createAddressLookupTable";
+export * from "./createAddressLookupTableWithNamespace";
 export * from "./extendAddressLookupTable";
+export * from "./extendAddressLookupTableWithArgs";
+export * from "./extendAddressLookupTableWithMode";
 export * from "./deactivateAddressLookupTable";
 export * from "./closeAddressLookupTable";
+export * from "./closeOrphanedWrapper";
+export * from "./rotateAddressLookupTable";
+export * from "./closePreviousAddressLookupTable";
+export * from "./freezeAddressLookupTable";
+export * from "./markInUse";
+export * from "./getLutStatus";
+export * from "./resolveAddressIndexes";
+export * from "./syncAddressLookupTable";
+export * from "./migrateWrapper";
+export * from "./setWrapperMetadata";
+export * from "./setOwnerAllowlist";
+export * from "./proposeAuthority";
+export * from "./acceptAuthority";
+export * from "./grantDelegate";
+export * from "./revokeDelegate";
+export * from "./createLutSet";
+export * from "./addLutSetMember";
+export * from "./removeLutSetMember";
+export * from "./extendLutSet";
+export * from "./initializeLutRegistry";
+export * from "./initializeConfig";
+export * from "./updateConfig";
+export * from "./setPaused";
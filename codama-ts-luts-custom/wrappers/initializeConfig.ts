@@ -0,0 +1,47 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getInitializeConfigInstruction } from "../../codama-ts-luts";
+import { getConfigAddress, getProgramDataAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildInitializeConfigInput = {
+  signer: PublicKey;
+  admin: PublicKey;
+  guardian?: PublicKey;
+  cooldownSlots: bigint | number;
+  maxLutsPerSigner: bigint | number;
+  treasury?: PublicKey;
+  createFeeLamports?: bigint | number;
+  extendFeeLamportsPerAddress?: bigint | number;
+};
+
+export function buildInitializeConfigInstruction({
+  signer,
+  admin,
+  guardian,
+  cooldownSlots,
+  maxLutsPerSigner,
+  treasury,
+  createFeeLamports,
+  extendFeeLamportsPerAddress,
+}: BuildInitializeConfigInput): TransactionInstruction {
+  const [config] = getConfigAddress();
+  const [programData] = getProgramDataAddress();
+
+  const ix = getInitializeConfigInstruction({
+    signer: toTransactionSigner(signer),
+    programData: address(programData.toBase58()),
+    config: address(config.toBase58()),
+    admin: address(admin.toBase58()),
+    guardian: address((guardian ?? PublicKey.default).toBase58()),
+    cooldownSlots: BigInt(cooldownSlots),
+    maxLutsPerSigner: BigInt(maxLutsPerSigner),
+    treasury: address((treasury ?? PublicKey.default).toBase58()),
+    createFeeLamports: BigInt(createFeeLamports ?? 0),
+    extendFeeLamportsPerAddress: BigInt(extendFeeLamportsPerAddress ?? 0),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
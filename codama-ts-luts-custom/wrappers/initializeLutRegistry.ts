@@ -0,0 +1,35 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getInitializeLutRegistryInstruction } from "../../codama-ts-luts";
+import { getLutRegistryAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildInitializeLutRegistryInput = {
+  signer: PublicKey;
+  nextId?: bigint | number;
+};
+
+export type BuildInitializeLutRegistryOutput = {
+  instruction: TransactionInstruction;
+  lutRegistry: PublicKey;
+};
+
+export function buildInitializeLutRegistryInstruction({
+  signer,
+  nextId,
+}: BuildInitializeLutRegistryInput): BuildInitializeLutRegistryOutput {
+  const [lutRegistry] = getLutRegistryAddress(signer);
+
+  const ix = getInitializeLutRegistryInstruction({
+    signer: toTransactionSigner(signer),
+    lutRegistry: address(lutRegistry.toBase58()),
+    nextId: BigInt(nextId ?? 0),
+  });
+
+  return {
+    instruction: toTransactionInstruction(
+      ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+    ),
+    lutRegistry,
+  };
+}
@@ -0,0 +1,23 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getMarkInUseInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildMarkInUseInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildMarkInUseInstruction({
+  signer,
+  userAddressLookupTable,
+}: BuildMarkInUseInput): TransactionInstruction {
+  const ix = getMarkInUseInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,23 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getMigrateWrapperInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildMigrateWrapperInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildMigrateWrapperInstruction({
+  signer,
+  userAddressLookupTable,
+}: BuildMigrateWrapperInput): TransactionInstruction {
+  const ix = getMigrateWrapperInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
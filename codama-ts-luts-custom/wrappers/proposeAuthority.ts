@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getProposeAuthorityInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildProposeAuthorityInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+  newAuthority: PublicKey;
+};
+
+export function buildProposeAuthorityInstruction({
+  signer,
+  userAddressLookupTable,
+  newAuthority,
+}: BuildProposeAuthorityInput): TransactionInstruction {
+  const ix = getProposeAuthorityInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    newAuthority: address(newAuthority.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getRemoveLutSetMemberInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildRemoveLutSetMemberInput = {
+  signer: PublicKey;
+  lutSet: PublicKey;
+  member: PublicKey;
+};
+
+export function buildRemoveLutSetMemberInstruction({
+  signer,
+  lutSet,
+  member,
+}: BuildRemoveLutSetMemberInput): TransactionInstruction {
+  const ix = getRemoveLutSetMemberInstruction({
+    signer: toTransactionSigner(signer),
+    lutSet: address(lutSet.toBase58()),
+    member: address(member.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
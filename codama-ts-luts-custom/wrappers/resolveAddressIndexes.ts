@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getResolveAddressIndexesInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildResolveAddressIndexesInput = {
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+  addresses: PublicKey[];
+};
+
+export function buildResolveAddressIndexesInstruction({
+  addressLookupTable,
+  userAddressLookupTable,
+  addresses,
+}: BuildResolveAddressIndexesInput): TransactionInstruction {
+  const ix = getResolveAddressIndexesInstruction({
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    addresses: addresses.map((addr) => address(addr.toBase58())),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getRevokeDelegateInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildRevokeDelegateInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+  delegate: PublicKey;
+};
+
+export function buildRevokeDelegateInstruction({
+  signer,
+  userAddressLookupTable,
+  delegate,
+}: BuildRevokeDelegateInput): TransactionInstruction {
+  const ix = getRevokeDelegateInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    delegate: address(delegate.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,52 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getRotateAddressLookupTableInstruction } from "../../codama-ts-luts";
+import { getConfigAddress, deriveAddressLookupTableAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildRotateAddressLookupTableInput = {
+  signer: PublicKey;
+  payer?: PublicKey;
+  treasury?: PublicKey;
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+  recentSlot: bigint | number;
+};
+
+export type BuildRotateAddressLookupTableOutput = {
+  instruction: TransactionInstruction;
+  newAddressLookupTable: PublicKey;
+};
+
+export function buildRotateAddressLookupTableInstruction({
+  signer,
+  payer,
+  treasury,
+  addressLookupTable,
+  userAddressLookupTable,
+  recentSlot,
+}: BuildRotateAddressLookupTableInput): BuildRotateAddressLookupTableOutput {
+  const [newAddressLookupTable] = deriveAddressLookupTableAddress(
+    userAddressLookupTable,
+    recentSlot
+  );
+  const [config] = getConfigAddress();
+
+  const ix = getRotateAddressLookupTableInstruction({
+    signer: toTransactionSigner(signer),
+    payer: payer ? toTransactionSigner(payer) : undefined,
+    config: address(config.toBase58()),
+    treasury: treasury ? address(treasury.toBase58()) : undefined,
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    newAddressLookupTable: address(newAddressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    recentSlot: BigInt(recentSlot),
+  });
+
+  return {
+    instruction: toTransactionInstruction(
+      ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+    ),
+    newAddressLookupTable,
+  };
+}
@@ -0,0 +1,26 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getSetOwnerAllowlistInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildSetOwnerAllowlistInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+  allowedOwners: PublicKey[];
+};
+
+export function buildSetOwnerAllowlistInstruction({
+  signer,
+  userAddressLookupTable,
+  allowedOwners,
+}: BuildSetOwnerAllowlistInput): TransactionInstruction {
+  const ix = getSetOwnerAllowlistInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    allowedOwners: allowedOwners.map((o) => address(o.toBase58())),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
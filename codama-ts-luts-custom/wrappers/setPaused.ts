@@ -0,0 +1,27 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getSetPausedInstruction } from "../../codama-ts-luts";
+import { getConfigAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildSetPausedInput = {
+  authority: PublicKey;
+  paused: boolean;
+};
+
+export function buildSetPausedInstruction({
+  authority,
+  paused,
+}: BuildSetPausedInput): TransactionInstruction {
+  const [config] = getConfigAddress();
+
+  const ix = getSetPausedInstruction({
+    authority: toTransactionSigner(authority),
+    config: address(config.toBase58()),
+    paused,
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
@@ -0,0 +1,29 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getSetWrapperMetadataInstruction } from "../../codama-ts-luts";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildSetWrapperMetadataInput = {
+  signer: PublicKey;
+  userAddressLookupTable: PublicKey;
+  label?: string;
+  metadataUri?: string;
+};
+
+export function buildSetWrapperMetadataInstruction({
+  signer,
+  userAddressLookupTable,
+  label,
+  metadataUri,
+}: BuildSetWrapperMetadataInput): TransactionInstruction {
+  const ix = getSetWrapperMetadataInstruction({
+    signer: toTransactionSigner(signer),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+    label: label ?? null,
+    metadataUri: metadataUri ?? null,
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
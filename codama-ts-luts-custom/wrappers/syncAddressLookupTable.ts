@@ -0,0 +1,27 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getSyncAddressLookupTableInstruction } from "../../codama-ts-luts";
+import { getConfigAddress } from "../pda";
+import { toTransactionInstruction } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildSyncAddressLookupTableInput = {
+  addressLookupTable: PublicKey;
+  userAddressLookupTable: PublicKey;
+};
+
+export function buildSyncAddressLookupTableInstruction({
+  addressLookupTable,
+  userAddressLookupTable,
+}: BuildSyncAddressLookupTableInput): TransactionInstruction {
+  const [config] = getConfigAddress();
+
+  const ix = getSyncAddressLookupTableInstruction({
+    config: address(config.toBase58()),
+    addressLookupTable: address(addressLookupTable.toBase58()),
+    userAddressLookupTable: address(userAddressLookupTable.toBase58()),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
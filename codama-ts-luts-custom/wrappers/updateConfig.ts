@@ -0,0 +1,50 @@
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+import { getUpdateConfigInstruction } from "../../codama-ts-luts";
+import { getConfigAddress } from "../pda";
+import { toTransactionInstruction, toTransactionSigner } from "../utils";
+import { address } from "@solana/kit";
+
+export type BuildUpdateConfigInput = {
+  admin: PublicKey;
+  newAdmin?: PublicKey;
+  guardian?: PublicKey;
+  cooldownSlots?: bigint | number;
+  maxLutsPerSigner?: bigint | number;
+  treasury?: PublicKey;
+  createFeeLamports?: bigint | number;
+  extendFeeLamportsPerAddress?: bigint | number;
+};
+
+export function buildUpdateConfigInstruction({
+  admin,
+  newAdmin,
+  guardian,
+  cooldownSlots,
+  maxLutsPerSigner,
+  treasury,
+  createFeeLamports,
+  extendFeeLamportsPerAddress,
+}: BuildUpdateConfigInput): TransactionInstruction {
+  const [config] = getConfigAddress();
+
+  const ix = getUpdateConfigInstruction({
+    admin: toTransactionSigner(admin),
+    config: address(config.toBase58()),
+    newAdmin: newAdmin ? address(newAdmin.toBase58()) : null,
+    guardian: guardian ? address(guardian.toBase58()) : null,
+    cooldownSlots: cooldownSlots === undefined ? null : BigInt(cooldownSlots),
+    maxLutsPerSigner:
+      maxLutsPerSigner === undefined ? null : BigInt(maxLutsPerSigner),
+    treasury: treasury ? address(treasury.toBase58()) : null,
+    createFeeLamports:
+      createFeeLamports === undefined ? null : BigInt(createFeeLamports),
+    extendFeeLamportsPerAddress:
+      extendFeeLamportsPerAddress === undefined
+        ? null
+        : BigInt(extendFeeLamportsPerAddress),
+  });
+
+  return toTransactionInstruction(
+    ix as unknown as Parameters<typeof toTransactionInstruction>[0]
+  );
+}
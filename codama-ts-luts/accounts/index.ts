@@ -6,4 +6,8 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./config";
+export * from "./lutCounter";
+export * from "./lutRegistry";
+export * from "./lutSet";
 export * from "./userAddressLookupTable";
@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   assertAccountExists,
   assertAccountsExist,
   combineCodec,
@@ -17,28 +19,47 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from "@solana/kit";
 
+import {
+  getLutDelegateDecoder,
+  getLutDelegateEncoder,
+  getLutStatusDecoder,
+  getLutStatusEncoder,
+  type LutDelegate,
+  type LutDelegateArgs,
+  type LutStatus,
+  type LutStatusArgs,
+} from "../types";
+
 export const USER_ADDRESS_LOOKUP_TABLE_DISCRIMINATOR = new Uint8Array([
   28, 183, 129, 166, 216, 32, 90, 100,
 ]);
@@ -57,6 +78,23 @@ export type UserAddressLookupTable = {
   id: bigint;
   addressLookupTable: Address;
   lastUpdatedSlot: bigint;
+  creator: Address;
+  pendingAuthority: Address;
+  delegates: Array<LutDelegate>;
+  status: LutStatus;
+  deactivatedSlot: bigint;
+  burstFill: boolean;
+  readyAtSlot: bigint;
+  previousAddressLookupTable: Address;
+  generation: bigint;
+  registered: boolean;
+  version: number;
+  label: string;
+  metadataUri: string;
+  namespace: Array<number>;
+  allowedOwners: Array<Address>;
+  allowedOwnerCount: number;
+  counted: boolean;
 };
 
 export type UserAddressLookupTableArgs = {
@@ -66,10 +104,27 @@ export type UserAddressLookupTableArgs = {
   id: number | bigint;
   addressLookupTable: Address;
   lastUpdatedSlot: number | bigint;
+  creator: Address;
+  pendingAuthority: Address;
+  delegates: Array<LutDelegateArgs>;
+  status: LutStatusArgs;
+  deactivatedSlot: number | bigint;
+  burstFill: boolean;
+  readyAtSlot: number | bigint;
+  previousAddressLookupTable: Address;
+  generation: number | bigint;
+  registered: boolean;
+  version: number;
+  label: string;
+  metadataUri: string;
+  namespace: Array<number>;
+  allowedOwners: Array<Address>;
+  allowedOwnerCount: number;
+  counted: boolean;
 };
 
 /** Gets the encoder for {@link UserAddressLookupTableArgs} account data. */
-export function getUserAddressLookupTableEncoder(): FixedSizeEncoder<UserAddressLookupTableArgs> {
+export function getUserAddressLookupTableEncoder(): Encoder<UserAddressLookupTableArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
@@ -79,6 +134,23 @@ export function getUserAddressLookupTableEncoder(): FixedSizeEncoder<UserAddress
       ["id", getU64Encoder()],
       ["addressLookupTable", getAddressEncoder()],
       ["lastUpdatedSlot", getU64Encoder()],
+      ["creator", getAddressEncoder()],
+      ["pendingAuthority", getAddressEncoder()],
+      ["delegates", getArrayEncoder(getLutDelegateEncoder(), { size: 4 })],
+      ["status", getLutStatusEncoder()],
+      ["deactivatedSlot", getU64Encoder()],
+      ["burstFill", getBooleanEncoder()],
+      ["readyAtSlot", getU64Encoder()],
+      ["previousAddressLookupTable", getAddressEncoder()],
+      ["generation", getU64Encoder()],
+      ["registered", getBooleanEncoder()],
+      ["version", getU8Encoder()],
+      ["label", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ["metadataUri", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ["namespace", getArrayEncoder(getU8Encoder(), { size: 32 })],
+      ["allowedOwners", getArrayEncoder(getAddressEncoder(), { size: 4 })],
+      ["allowedOwnerCount", getU8Encoder()],
+      ["counted", getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -88,7 +160,7 @@ export function getUserAddressLookupTableEncoder(): FixedSizeEncoder<UserAddress
 }
 
 /** Gets the decoder for {@link UserAddressLookupTable} account data. */
-export function getUserAddressLookupTableDecoder(): FixedSizeDecoder<UserAddressLookupTable> {
+export function getUserAddressLookupTableDecoder(): Decoder<UserAddressLookupTable> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["bump", getU8Decoder()],
@@ -97,11 +169,28 @@ export function getUserAddressLookupTableDecoder(): FixedSizeDecoder<UserAddress
     ["id", getU64Decoder()],
     ["addressLookupTable", getAddressDecoder()],
     ["lastUpdatedSlot", getU64Decoder()],
+    ["creator", getAddressDecoder()],
+    ["pendingAuthority", getAddressDecoder()],
+    ["delegates", getArrayDecoder(getLutDelegateDecoder(), { size: 4 })],
+    ["status", getLutStatusDecoder()],
+    ["deactivatedSlot", getU64Decoder()],
+    ["burstFill", getBooleanDecoder()],
+    ["readyAtSlot", getU64Decoder()],
+    ["previousAddressLookupTable", getAddressDecoder()],
+    ["generation", getU64Decoder()],
+    ["registered", getBooleanDecoder()],
+    ["version", getU8Decoder()],
+    ["label", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ["metadataUri", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ["namespace", getArrayDecoder(getU8Decoder(), { size: 32 })],
+    ["allowedOwners", getArrayDecoder(getAddressDecoder(), { size: 4 })],
+    ["allowedOwnerCount", getU8Decoder()],
+    ["counted", getBooleanDecoder()],
   ]);
 }
 
 /** Gets the codec for {@link UserAddressLookupTable} account data. */
-export function getUserAddressLookupTableCodec(): FixedSizeCodec<
+export function getUserAddressLookupTableCodec(): Codec<
   UserAddressLookupTableArgs,
   UserAddressLookupTable
 > {
@@ -179,7 +268,3 @@ export async function fetchAllMaybeUserAddressLookupTable(
     decodeUserAddressLookupTable(maybeAccount)
   );
 }
-
-export function getUserAddressLookupTableSize(): number {
-  return 97;
-}
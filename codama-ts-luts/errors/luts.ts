@@ -22,20 +22,140 @@ export const LUTS_ERROR__LUT_NOT_READY = 0x1771; // 6001
 export const LUTS_ERROR__MAX_ADDRESSES_EXCEEDED = 0x1772; // 6002
 /** NoNewAddresses: No new addresses to add */
 export const LUTS_ERROR__NO_NEW_ADDRESSES = 0x1773; // 6003
+/** NotPendingAuthority: Signer is not the pending authority */
+export const LUTS_ERROR__NOT_PENDING_AUTHORITY = 0x1774; // 6004
+/** UnauthorizedExtender: Signer is neither the LUT owner nor an active delegate */
+export const LUTS_ERROR__UNAUTHORIZED_EXTENDER = 0x1775; // 6005
+/** MaxDelegatesExceeded: Maximum delegates exceeded */
+export const LUTS_ERROR__MAX_DELEGATES_EXCEEDED = 0x1776; // 6006
+/** DelegateNotFound: Delegate not found */
+export const LUTS_ERROR__DELEGATE_NOT_FOUND = 0x1777; // 6007
+/** InvalidDelegateExpiry: Delegate expiry slot must be in the future */
+export const LUTS_ERROR__INVALID_DELEGATE_EXPIRY = 0x1778; // 6008
+/** LutFrozen: LUT is frozen and can no longer be modified */
+export const LUTS_ERROR__LUT_FROZEN = 0x1779; // 6009
+/** LutDeactivated: LUT is deactivated */
+export const LUTS_ERROR__LUT_DEACTIVATED = 0x177a; // 6010
+/** NotInBurstFill: LUT is not in burst-fill mode */
+export const LUTS_ERROR__NOT_IN_BURST_FILL = 0x177b; // 6011
+/** InvalidConfig: Invalid config parameters */
+export const LUTS_ERROR__INVALID_CONFIG = 0x177c; // 6012
+/** MaxLutsPerSignerExceeded: Maximum LUTs per signer exceeded */
+export const LUTS_ERROR__MAX_LUTS_PER_SIGNER_EXCEEDED = 0x177d; // 6013
+/** ProgramPaused: Program is paused */
+export const LUTS_ERROR__PROGRAM_PAUSED = 0x177e; // 6014
+/** NotPauseAuthority: Signer is not allowed to change the pause state */
+export const LUTS_ERROR__NOT_PAUSE_AUTHORITY = 0x177f; // 6015
+/** MissingTreasury: Treasury account is required when a fee is charged */
+export const LUTS_ERROR__MISSING_TREASURY = 0x1780; // 6016
+/** InvalidTreasury: Treasury does not match the config */
+export const LUTS_ERROR__INVALID_TREASURY = 0x1781; // 6017
+/** PreviousLutPending: The previous LUT must be closed first */
+export const LUTS_ERROR__PREVIOUS_LUT_PENDING = 0x1782; // 6018
+/** NoPreviousLut: Wrapper has no previous LUT */
+export const LUTS_ERROR__NO_PREVIOUS_LUT = 0x1783; // 6019
+/** LutSetFull: LUT set has no free member slots */
+export const LUTS_ERROR__LUT_SET_FULL = 0x1784; // 6020
+/** LutSetMemberExists: Wrapper is already a member of the LUT set */
+export const LUTS_ERROR__LUT_SET_MEMBER_EXISTS = 0x1785; // 6021
+/** InvalidLutSetMember: Accounts do not match the LUT set members */
+export const LUTS_ERROR__INVALID_LUT_SET_MEMBER = 0x1786; // 6022
+/** LutIdBelowNext: id is below the registry's next_id */
+export const LUTS_ERROR__LUT_ID_BELOW_NEXT = 0x1787; // 6023
+/** MissingLutRegistry: LUT registry is required for a registered LUT */
+export const LUTS_ERROR__MISSING_LUT_REGISTRY = 0x1788; // 6024
+/** LutNotOrphaned: Native LUT still exists; use close_address_lookup_table */
+export const LUTS_ERROR__LUT_NOT_ORPHANED = 0x1789; // 6025
+/** WrapperNeedsMigration: Wrapper uses the legacy layout; call migrate_wrapper first */
+export const LUTS_ERROR__WRAPPER_NEEDS_MIGRATION = 0x178a; // 6026
+/** WrapperAlreadyMigrated: Wrapper already uses the current layout */
+export const LUTS_ERROR__WRAPPER_ALREADY_MIGRATED = 0x178b; // 6027
+/** WrapperMetadataTooLong: Label or metadata URI is too long */
+export const LUTS_ERROR__WRAPPER_METADATA_TOO_LONG = 0x178c; // 6028
+/** InvalidNamespace: Namespace must not be all zeroes */
+export const LUTS_ERROR__INVALID_NAMESPACE = 0x178d; // 6029
+/** OwnerNotAllowed: Address is not owned by an allowed program */
+export const LUTS_ERROR__OWNER_NOT_ALLOWED = 0x178e; // 6030
+/** MaxAllowedOwnersExceeded: Maximum allowed owners exceeded */
+export const LUTS_ERROR__MAX_ALLOWED_OWNERS_EXCEEDED = 0x178f; // 6031
+/** MissingLutCounter: LUT counter is missing or does not belong to the creator */
+export const LUTS_ERROR__MISSING_LUT_COUNTER = 0x1790; // 6032
+/** LutIdOverflow: LUT id is the largest possible id; the registry has no next id */
+export const LUTS_ERROR__LUT_ID_OVERFLOW = 0x1791; // 6033
 
 export type LutsError =
+  | typeof LUTS_ERROR__DELEGATE_NOT_FOUND
+  | typeof LUTS_ERROR__INVALID_CONFIG
+  | typeof LUTS_ERROR__INVALID_DELEGATE_EXPIRY
   | typeof LUTS_ERROR__INVALID_LOOKUP_TABLE
+  | typeof LUTS_ERROR__INVALID_LUT_SET_MEMBER
+  | typeof LUTS_ERROR__INVALID_NAMESPACE
+  | typeof LUTS_ERROR__INVALID_TREASURY
+  | typeof LUTS_ERROR__LUT_DEACTIVATED
+  | typeof LUTS_ERROR__LUT_FROZEN
+  | typeof LUTS_ERROR__LUT_ID_BELOW_NEXT
+  | typeof LUTS_ERROR__LUT_ID_OVERFLOW
+  | typeof LUTS_ERROR__LUT_NOT_ORPHANED
   | typeof LUTS_ERROR__LUT_NOT_READY
+  | typeof LUTS_ERROR__LUT_SET_FULL
+  | typeof LUTS_ERROR__LUT_SET_MEMBER_EXISTS
   | typeof LUTS_ERROR__MAX_ADDRESSES_EXCEEDED
-  | typeof LUTS_ERROR__NO_NEW_ADDRESSES;
+  | typeof LUTS_ERROR__MAX_ALLOWED_OWNERS_EXCEEDED
+  | typeof LUTS_ERROR__MAX_DELEGATES_EXCEEDED
+  | typeof LUTS_ERROR__MAX_LUTS_PER_SIGNER_EXCEEDED
+  | typeof LUTS_ERROR__MISSING_LUT_COUNTER
+  | typeof LUTS_ERROR__MISSING_LUT_REGISTRY
+  | typeof LUTS_ERROR__MISSING_TREASURY
+  | typeof LUTS_ERROR__NO_NEW_ADDRESSES
+  | typeof LUTS_ERROR__NO_PREVIOUS_LUT
+  | typeof LUTS_ERROR__NOT_IN_BURST_FILL
+  | typeof LUTS_ERROR__NOT_PAUSE_AUTHORITY
+  | typeof LUTS_ERROR__NOT_PENDING_AUTHORITY
+  | typeof LUTS_ERROR__OWNER_NOT_ALLOWED
+  | typeof LUTS_ERROR__PREVIOUS_LUT_PENDING
+  | typeof LUTS_ERROR__PROGRAM_PAUSED
+  | typeof LUTS_ERROR__UNAUTHORIZED_EXTENDER
+  | typeof LUTS_ERROR__WRAPPER_ALREADY_MIGRATED
+  | typeof LUTS_ERROR__WRAPPER_METADATA_TOO_LONG
+  | typeof LUTS_ERROR__WRAPPER_NEEDS_MIGRATION;
 
 let lutsErrorMessages: Record<LutsError, string> | undefined;
 if (process.env.NODE_ENV !== "production") {
   lutsErrorMessages = {
+    [LUTS_ERROR__DELEGATE_NOT_FOUND]: `Delegate not found`,
+    [LUTS_ERROR__INVALID_CONFIG]: `Invalid config parameters`,
+    [LUTS_ERROR__INVALID_DELEGATE_EXPIRY]: `Delegate expiry slot must be in the future`,
     [LUTS_ERROR__INVALID_LOOKUP_TABLE]: `Invalid Lookup Table address`,
+    [LUTS_ERROR__INVALID_LUT_SET_MEMBER]: `Accounts do not match the LUT set members`,
+    [LUTS_ERROR__INVALID_NAMESPACE]: `Namespace must not be all zeroes`,
+    [LUTS_ERROR__INVALID_TREASURY]: `Treasury does not match the config`,
+    [LUTS_ERROR__LUT_DEACTIVATED]: `LUT is deactivated`,
+    [LUTS_ERROR__LUT_FROZEN]: `LUT is frozen and can no longer be modified`,
+    [LUTS_ERROR__LUT_ID_BELOW_NEXT]: `id is below the registry's next_id`,
+    [LUTS_ERROR__LUT_ID_OVERFLOW]: `LUT id is the largest possible id; the registry has no next id`,
+    [LUTS_ERROR__LUT_NOT_ORPHANED]: `Native LUT still exists; use close_address_lookup_table`,
     [LUTS_ERROR__LUT_NOT_READY]: `LUT not yet ready - cooldown period not passed`,
+    [LUTS_ERROR__LUT_SET_FULL]: `LUT set has no free member slots`,
+    [LUTS_ERROR__LUT_SET_MEMBER_EXISTS]: `Wrapper is already a member of the LUT set`,
     [LUTS_ERROR__MAX_ADDRESSES_EXCEEDED]: `Maximum addresses exceeded (256 limit)`,
+    [LUTS_ERROR__MAX_ALLOWED_OWNERS_EXCEEDED]: `Maximum allowed owners exceeded`,
+    [LUTS_ERROR__MAX_DELEGATES_EXCEEDED]: `Maximum delegates exceeded`,
+    [LUTS_ERROR__MAX_LUTS_PER_SIGNER_EXCEEDED]: `Maximum LUTs per signer exceeded`,
+    [LUTS_ERROR__MISSING_LUT_COUNTER]: `LUT counter is missing or does not belong to the creator`,
+    [LUTS_ERROR__MISSING_LUT_REGISTRY]: `LUT registry is required for a registered LUT`,
+    [LUTS_ERROR__MISSING_TREASURY]: `Treasury account is required when a fee is charged`,
     [LUTS_ERROR__NO_NEW_ADDRESSES]: `No new addresses to add`,
+    [LUTS_ERROR__NO_PREVIOUS_LUT]: `Wrapper has no previous LUT`,
+    [LUTS_ERROR__NOT_IN_BURST_FILL]: `LUT is not in burst-fill mode`,
+    [LUTS_ERROR__NOT_PAUSE_AUTHORITY]: `Signer is not allowed to change the pause state`,
+    [LUTS_ERROR__NOT_PENDING_AUTHORITY]: `Signer is not the pending authority`,
+    [LUTS_ERROR__OWNER_NOT_ALLOWED]: `Address is not owned by an allowed program`,
+    [LUTS_ERROR__PREVIOUS_LUT_PENDING]: `The previous LUT must be closed first`,
+    [LUTS_ERROR__PROGRAM_PAUSED]: `Program is paused`,
+    [LUTS_ERROR__UNAUTHORIZED_EXTENDER]: `Signer is neither the LUT owner nor an active delegate`,
+    [LUTS_ERROR__WRAPPER_ALREADY_MIGRATED]: `Wrapper already uses the current layout`,
+    [LUTS_ERROR__WRAPPER_METADATA_TOO_LONG]: `Label or metadata URI is too long`,
+    [LUTS_ERROR__WRAPPER_NEEDS_MIGRATION]: `Wrapper uses the legacy layout; call migrate_wrapper first`,
   };
 }
 
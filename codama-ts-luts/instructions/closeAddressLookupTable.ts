@@ -46,6 +46,7 @@ export function getCloseAddressLookupTableDiscriminatorBytes() {
 export type CloseAddressLookupTableInstruction<
   TProgram extends string = typeof LUTS_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountRentDestination extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = "11111111111111111111111111111111",
@@ -54,6 +55,8 @@ export type CloseAddressLookupTableInstruction<
     | AccountMeta<string> = "AddressLookupTab1e1111111111111111111111111",
   TAccountAddressLookupTable extends string | AccountMeta<string> = string,
   TAccountUserAddressLookupTable extends string | AccountMeta<string> = string,
+  TAccountLutRegistry extends string | AccountMeta<string> = string,
+  TAccountLutCounter extends string | AccountMeta<string> = string,
   TAccountRent extends
     | string
     | AccountMeta<string> = "SysvarRent111111111111111111111111111111111",
@@ -66,6 +69,9 @@ export type CloseAddressLookupTableInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountRentDestination extends string
+        ? WritableAccount<TAccountRentDestination>
+        : TAccountRentDestination,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -78,6 +84,12 @@ export type CloseAddressLookupTableInstruction<
       TAccountUserAddressLookupTable extends string
         ? WritableAccount<TAccountUserAddressLookupTable>
         : TAccountUserAddressLookupTable,
+      TAccountLutRegistry extends string
+        ? WritableAccount<TAccountLutRegistry>
+        : TAccountLutRegistry,
+      TAccountLutCounter extends string
+        ? WritableAccount<TAccountLutCounter>
+        : TAccountLutCounter,
       TAccountRent extends string
         ? ReadonlyAccount<TAccountRent>
         : TAccountRent,
@@ -119,45 +131,60 @@ export function getCloseAddressLookupTableInstructionDataCodec(): FixedSizeCodec
 
 export type CloseAddressLookupTableInput<
   TAccountSigner extends string = string,
+  TAccountRentDestination extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountAddressLookupTableProgram extends string = string,
   TAccountAddressLookupTable extends string = string,
   TAccountUserAddressLookupTable extends string = string,
+  TAccountLutRegistry extends string = string,
+  TAccountLutCounter extends string = string,
   TAccountRent extends string = string
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  rentDestination?: Address<TAccountRentDestination>;
   systemProgram?: Address<TAccountSystemProgram>;
   addressLookupTableProgram?: Address<TAccountAddressLookupTableProgram>;
   addressLookupTable: Address<TAccountAddressLookupTable>;
   userAddressLookupTable: Address<TAccountUserAddressLookupTable>;
+  lutRegistry?: Address<TAccountLutRegistry>;
+  lutCounter?: Address<TAccountLutCounter>;
   rent?: Address<TAccountRent>;
 };
 
 export function getCloseAddressLookupTableInstruction<
   TAccountSigner extends string,
+  TAccountRentDestination extends string,
   TAccountSystemProgram extends string,
   TAccountAddressLookupTableProgram extends string,
   TAccountAddressLookupTable extends string,
   TAccountUserAddressLookupTable extends string,
+  TAccountLutRegistry extends string,
+  TAccountLutCounter extends string,
   TAccountRent extends string,
   TProgramAddress extends Address = typeof LUTS_PROGRAM_ADDRESS
 >(
   input: CloseAddressLookupTableInput<
     TAccountSigner,
+    TAccountRentDestination,
     TAccountSystemProgram,
     TAccountAddressLookupTableProgram,
     TAccountAddressLookupTable,
     TAccountUserAddressLookupTable,
+    TAccountLutRegistry,
+    TAccountLutCounter,
     TAccountRent
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseAddressLookupTableInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountRentDestination,
   TAccountSystemProgram,
   TAccountAddressLookupTableProgram,
   TAccountAddressLookupTable,
   TAccountUserAddressLookupTable,
+  TAccountLutRegistry,
+  TAccountLutCounter,
   TAccountRent
 > {
   // Program address.
@@ -166,6 +193,7 @@ export function getCloseAddressLookupTableInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    rentDestination: { value: input.rentDestination ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     addressLookupTableProgram: {
       value: input.addressLookupTableProgram ?? null,
@@ -179,6 +207,8 @@ export function getCloseAddressLookupTableInstruction<
       value: input.userAddressLookupTable ?? null,
       isWritable: true,
     },
+    lutRegistry: { value: input.lutRegistry ?? null, isWritable: true },
+    lutCounter: { value: input.lutCounter ?? null, isWritable: true },
     rent: { value: input.rent ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -204,15 +234,18 @@ export function getCloseAddressLookupTableInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.rentDestination),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.addressLookupTableProgram),
       getAccountMeta(accounts.addressLookupTable),
       getAccountMeta(accounts.userAddressLookupTable),
+      getAccountMeta(accounts.lutRegistry),
+      getAccountMeta(accounts.lutCounter),
       getAccountMeta(accounts.rent),
     ],
     data: getCloseAddressLookupTableInstructionDataEncoder().encode({}),
     programAddress,
-  } as CloseAddressLookupTableInstruction<TProgramAddress, TAccountSigner, TAccountSystemProgram, TAccountAddressLookupTableProgram, TAccountAddressLookupTable, TAccountUserAddressLookupTable, TAccountRent>);
+  } as CloseAddressLookupTableInstruction<TProgramAddress, TAccountSigner, TAccountRentDestination, TAccountSystemProgram, TAccountAddressLookupTableProgram, TAccountAddressLookupTable, TAccountUserAddressLookupTable, TAccountLutRegistry, TAccountLutCounter, TAccountRent>);
 }
 
 export type ParsedCloseAddressLookupTableInstruction<
@@ -222,11 +255,14 @@ export type ParsedCloseAddressLookupTableInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     signer: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    addressLookupTableProgram: TAccountMetas[2];
-    addressLookupTable: TAccountMetas[3];
-    userAddressLookupTable: TAccountMetas[4];
-    rent: TAccountMetas[5];
+    rentDestination?: TAccountMetas[1] | undefined;
+    systemProgram: TAccountMetas[2];
+    addressLookupTableProgram: TAccountMetas[3];
+    addressLookupTable: TAccountMetas[4];
+    userAddressLookupTable: TAccountMetas[5];
+    lutRegistry?: TAccountMetas[6] | undefined;
+    lutCounter?: TAccountMetas[7] | undefined;
+    rent: TAccountMetas[8];
   };
   data: CloseAddressLookupTableInstructionData;
 };
@@ -239,7 +275,7 @@ export function parseCloseAddressLookupTableInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseAddressLookupTableInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -249,14 +285,23 @@ export function parseCloseAddressLookupTableInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === LUTS_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      rentDestination: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
       addressLookupTableProgram: getNextAccount(),
       addressLookupTable: getNextAccount(),
       userAddressLookupTable: getNextAccount(),
+      lutRegistry: getNextOptionalAccount(),
+      lutCounter: getNextOptionalAccount(),
       rent: getNextAccount(),
     },
     data: getCloseAddressLookupTableInstructionDataDecoder().decode(
@@ -7,25 +7,41 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -48,6 +64,9 @@ export function getCreateAddressLookupTableDiscriminatorBytes() {
 export type CreateAddressLookupTableInstruction<
   TProgram extends string = typeof LUTS_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountTreasury extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = "11111111111111111111111111111111",
@@ -55,6 +74,8 @@ export type CreateAddressLookupTableInstruction<
     | string
     | AccountMeta<string> = "AddressLookupTab1e1111111111111111111111111",
   TAccountAddressLookupTable extends string | AccountMeta<string> = string,
+  TAccountLutRegistry extends string | AccountMeta<string> = string,
+  TAccountLutCounter extends string | AccountMeta<string> = string,
   TAccountUserAddressLookupTable extends string | AccountMeta<string> = string,
   TAccountRent extends
     | string
@@ -68,6 +89,16 @@ export type CreateAddressLookupTableInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountTreasury extends string
+        ? WritableAccount<TAccountTreasury>
+        : TAccountTreasury,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -77,6 +108,12 @@ export type CreateAddressLookupTableInstruction<
       TAccountAddressLookupTable extends string
         ? WritableAccount<TAccountAddressLookupTable>
         : TAccountAddressLookupTable,
+      TAccountLutRegistry extends string
+        ? WritableAccount<TAccountLutRegistry>
+        : TAccountLutRegistry,
+      TAccountLutCounter extends string
+        ? WritableAccount<TAccountLutCounter>
+        : TAccountLutCounter,
       TAccountUserAddressLookupTable extends string
         ? WritableAccount<TAccountUserAddressLookupTable>
         : TAccountUserAddressLookupTable,
@@ -91,19 +128,44 @@ export type CreateAddressLookupTableInstructionData = {
   discriminator: ReadonlyUint8Array;
   recentSlot: bigint;
   id: bigint;
+  initialAddresses: Option<Array<Address>>;
+  burstFill: Option<boolean>;
+  label: Option<string>;
+  metadataUri: Option<string>;
 };
 
 export type CreateAddressLookupTableInstructionDataArgs = {
   recentSlot: number | bigint;
   id: number | bigint;
+  initialAddresses: OptionOrNullable<Array<Address>>;
+  burstFill: OptionOrNullable<boolean>;
+  label: OptionOrNullable<string>;
+  metadataUri: OptionOrNullable<string>;
 };
 
-export function getCreateAddressLookupTableInstructionDataEncoder(): FixedSizeEncoder<CreateAddressLookupTableInstructionDataArgs> {
+export function getCreateAddressLookupTableInstructionDataEncoder(): Encoder<CreateAddressLookupTableInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["recentSlot", getU64Encoder()],
       ["id", getU64Encoder()],
+      [
+        "initialAddresses",
+        getOptionEncoder(getArrayEncoder(getAddressEncoder())),
+      ],
+      ["burstFill", getOptionEncoder(getBooleanEncoder())],
+      [
+        "label",
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
+      [
+        "metadataUri",
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
     ]),
     (value) => ({
       ...value,
@@ -112,15 +174,28 @@ export function getCreateAddressLookupTableInstructionDataEncoder(): FixedSizeEn
   );
 }
 
-export function getCreateAddressLookupTableInstructionDataDecoder(): FixedSizeDecoder<CreateAddressLookupTableInstructionData> {
+export function getCreateAddressLookupTableInstructionDataDecoder(): Decoder<CreateAddressLookupTableInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["recentSlot", getU64Decoder()],
     ["id", getU64Decoder()],
+    [
+      "initialAddresses",
+      getOptionDecoder(getArrayDecoder(getAddressDecoder())),
+    ],
+    ["burstFill", getOptionDecoder(getBooleanDecoder())],
+    [
+      "label",
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
+    [
+      "metadataUri",
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
   ]);
 }
 
-export function getCreateAddressLookupTableInstructionDataCodec(): FixedSizeCodec<
+export function getCreateAddressLookupTableInstructionDataCodec(): Codec<
   CreateAddressLookupTableInstructionDataArgs,
   CreateAddressLookupTableInstructionData
 > {
@@ -132,36 +207,60 @@ export function getCreateAddressLookupTableInstructionDataCodec(): FixedSizeCode
 
 export type CreateAddressLookupTableInput<
   TAccountSigner extends string = string,
+  TAccountPayer extends string = string,
+  TAccountConfig extends string = string,
+  TAccountTreasury extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountAddressLookupTableProgram extends string = string,
   TAccountAddressLookupTable extends string = string,
+  TAccountLutRegistry extends string = string,
+  TAccountLutCounter extends string = string,
   TAccountUserAddressLookupTable extends string = string,
   TAccountRent extends string = string
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  payer?: TransactionSigner<TAccountPayer>;
+  config: Address<TAccountConfig>;
+  treasury?: Address<TAccountTreasury>;
   systemProgram?: Address<TAccountSystemProgram>;
   addressLookupTableProgram?: Address<TAccountAddressLookupTableProgram>;
   addressLookupTable: Address<TAccountAddressLookupTable>;
+  lutRegistry?: Address<TAccountLutRegistry>;
+  lutCounter: Address<TAccountLutCounter>;
   userAddressLookupTable: Address<TAccountUserAddressLookupTable>;
   rent?: Address<TAccountRent>;
   recentSlot: CreateAddressLookupTableInstructionDataArgs["recentSlot"];
   id: CreateAddressLookupTableInstructionDataArgs["id"];
+  initialAddresses: CreateAddressLookupTableInstructionDataArgs["initialAddresses"];
+  burstFill: CreateAddressLookupTableInstructionDataArgs["burstFill"];
+  label: CreateAddressLookupTableInstructionDataArgs["label"];
+  metadataUri: CreateAddressLookupTableInstructionDataArgs["metadataUri"];
 };
 
 export function getCreateAddressLookupTableInstruction<
   TAccountSigner extends string,
+  TAccountPayer extends string,
+  TAccountConfig extends string,
+  TAccountTreasury extends string,
   TAccountSystemProgram extends string,
   TAccountAddressLookupTableProgram extends string,
   TAccountAddressLookupTable extends string,
+  TAccountLutRegistry extends string,
+  TAccountLutCounter extends string,
   TAccountUserAddressLookupTable extends string,
   TAccountRent extends string,
   TProgramAddress extends Address = typeof LUTS_PROGRAM_ADDRESS
 >(
   input: CreateAddressLookupTableInput<
     TAccountSigner,
+    TAccountPayer,
+    TAccountConfig,
+    TAccountTreasury,
     TAccountSystemProgram,
     TAccountAddressLookupTableProgram,
     TAccountAddressLookupTable,
+    TAccountLutRegistry,
+    TAccountLutCounter,
     TAccountUserAddressLookupTable,
     TAccountRent
   >,
@@ -169,9 +268,14 @@ export function getCreateAddressLookupTableInstruction<
 ): CreateAddressLookupTableInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountPayer,
+  TAccountConfig,
+  TAccountTreasury,
   TAccountSystemProgram,
   TAccountAddressLookupTableProgram,
   TAccountAddressLookupTable,
+  TAccountLutRegistry,
+  TAccountLutCounter,
   TAccountUserAddressLookupTable,
   TAccountRent
 > {
@@ -181,6 +285,9 @@ export function getCreateAddressLookupTableInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    treasury: { value: input.treasury ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     addressLookupTableProgram: {
       value: input.addressLookupTableProgram ?? null,
@@ -190,6 +297,8 @@ export function getCreateAddressLookupTableInstruction<
       value: input.addressLookupTable ?? null,
       isWritable: true,
     },
+    lutRegistry: { value: input.lutRegistry ?? null, isWritable: true },
+    lutCounter: { value: input.lutCounter ?? null, isWritable: true },
     userAddressLookupTable: {
       value: input.userAddressLookupTable ?? null,
       isWritable: true,
@@ -222,9 +331,14 @@ export function getCreateAddressLookupTableInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.addressLookupTableProgram),
       getAccountMeta(accounts.addressLookupTable),
+      getAccountMeta(accounts.lutRegistry),
+      getAccountMeta(accounts.lutCounter),
       getAccountMeta(accounts.userAddressLookupTable),
       getAccountMeta(accounts.rent),
     ],
@@ -232,7 +346,7 @@ export function getCreateAddressLookupTableInstruction<
       args as CreateAddressLookupTableInstructionDataArgs
     ),
     programAddress,
-  } as CreateAddressLookupTableInstruction<TProgramAddress, TAccountSigner, TAccountSystemProgram, TAccountAddressLookupTableProgram, TAccountAddressLookupTable, TAccountUserAddressLookupTable, TAccountRent>);
+  } as CreateAddressLookupTableInstruction<TProgramAddress, TAccountSigner, TAccountPayer, TAccountConfig, TAccountTreasury, TAccountSystemProgram, TAccountAddressLookupTableProgram, TAccountAddressLookupTable, TAccountLutRegistry, TAccountLutCounter, TAccountUserAddressLookupTable, TAccountRent>);
 }
 
 export type ParsedCreateAddressLookupTableInstruction<
@@ -242,11 +356,16 @@ export type ParsedCreateAddressLookupTableInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     signer: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    addressLookupTableProgram: TAccountMetas[2];
-    addressLookupTable: TAccountMetas[3];
-    userAddressLookupTable: TAccountMetas[4];
-    rent: TAccountMetas[5];
+    payer?: TAccountMetas[1] | undefined;
+    config: TAccountMetas[2];
+    treasury?: TAccountMetas[3] | undefined;
+    systemProgram: TAccountMetas[4];
+    addressLookupTableProgram: TAccountMetas[5];
+    addressLookupTable: TAccountMetas[6];
+    lutRegistry?: TAccountMetas[7] | undefined;
+    lutCounter: TAccountMetas[8];
+    userAddressLookupTable: TAccountMetas[9];
+    rent: TAccountMetas[10];
   };
   data: CreateAddressLookupTableInstructionData;
 };
@@ -259,7 +378,7 @@ export function parseCreateAddressLookupTableInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateAddressLookupTableInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -269,13 +388,24 @@ export function parseCreateAddressLookupTableInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === LUTS_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      payer: getNextOptionalAccount(),
+      config: getNextAccount(),
+      treasury: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
       addressLookupTableProgram: getNextAccount(),
       addressLookupTable: getNextAccount(),
+      lutRegistry: getNextOptionalAccount(),
+      lutCounter: getNextAccount(),
       userAddressLookupTable: getNextAccount(),
       rent: getNextAccount(),
     },
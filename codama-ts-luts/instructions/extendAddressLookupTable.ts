@@ -46,6 +46,9 @@ export function getExtendAddressLookupTableDiscriminatorBytes() {
 export type ExtendAddressLookupTableInstruction<
   TProgram extends string = typeof LUTS_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountTreasury extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = "11111111111111111111111111111111",
@@ -66,6 +69,16 @@ export type ExtendAddressLookupTableInstruction<
         ? WritableSignerAccount<TAccountSigner> &
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountTreasury extends string
+        ? WritableAccount<TAccountTreasury>
+        : TAccountTreasury,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -119,6 +132,9 @@ export function getExtendAddressLookupTableInstructionDataCodec(): FixedSizeCode
 
 export type ExtendAddressLookupTableInput<
   TAccountSigner extends string = string,
+  TAccountPayer extends string = string,
+  TAccountConfig extends string = string,
+  TAccountTreasury extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountAddressLookupTableProgram extends string = string,
   TAccountAddressLookupTable extends string = string,
@@ -126,6 +142,9 @@ export type ExtendAddressLookupTableInput<
   TAccountRent extends string = string
 > = {
   signer: TransactionSigner<TAccountSigner>;
+  payer?: TransactionSigner<TAccountPayer>;
+  config: Address<TAccountConfig>;
+  treasury?: Address<TAccountTreasury>;
   systemProgram?: Address<TAccountSystemProgram>;
   addressLookupTableProgram?: Address<TAccountAddressLookupTableProgram>;
   addressLookupTable: Address<TAccountAddressLookupTable>;
@@ -135,6 +154,9 @@ export type ExtendAddressLookupTableInput<
 
 export function getExtendAddressLookupTableInstruction<
   TAccountSigner extends string,
+  TAccountPayer extends string,
+  TAccountConfig extends string,
+  TAccountTreasury extends string,
   TAccountSystemProgram extends string,
   TAccountAddressLookupTableProgram extends string,
   TAccountAddressLookupTable extends string,
@@ -144,6 +166,9 @@ export function getExtendAddressLookupTableInstruction<
 >(
   input: ExtendAddressLookupTableInput<
     TAccountSigner,
+    TAccountPayer,
+    TAccountConfig,
+    TAccountTreasury,
     TAccountSystemProgram,
     TAccountAddressLookupTableProgram,
     TAccountAddressLookupTable,
@@ -154,6 +179,9 @@ export function getExtendAddressLookupTableInstruction<
 ): ExtendAddressLookupTableInstruction<
   TProgramAddress,
   TAccountSigner,
+  TAccountPayer,
+  TAccountConfig,
+  TAccountTreasury,
   TAccountSystemProgram,
   TAccountAddressLookupTableProgram,
   TAccountAddressLookupTable,
@@ -166,6 +194,9 @@ export function getExtendAddressLookupTableInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    treasury: { value: input.treasury ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     addressLookupTableProgram: {
       value: input.addressLookupTableProgram ?? null,
@@ -204,6 +235,9 @@ export function getExtendAddressLookupTableInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.signer),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.addressLookupTableProgram),
       getAccountMeta(accounts.addressLookupTable),
@@ -212,7 +246,7 @@ export function getExtendAddressLookupTableInstruction<
     ],
     data: getExtendAddressLookupTableInstructionDataEncoder().encode({}),
     programAddress,
-  } as ExtendAddressLookupTableInstruction<TProgramAddress, TAccountSigner, TAccountSystemProgram, TAccountAddressLookupTableProgram, TAccountAddressLookupTable, TAccountUserAddressLookupTable, TAccountRent>);
+  } as ExtendAddressLookupTableInstruction<TProgramAddress, TAccountSigner, TAccountPayer, TAccountConfig, TAccountTreasury, TAccountSystemProgram, TAccountAddressLookupTableProgram, TAccountAddressLookupTable, TAccountUserAddressLookupTable, TAccountRent>);
 }
 
 export type ParsedExtendAddressLookupTableInstruction<
@@ -222,11 +256,14 @@ export type ParsedExtendAddressLookupTableInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     signer: TAccountMetas[0];
-    systemProgram: TAccountMetas[1];
-    addressLookupTableProgram: TAccountMetas[2];
-    addressLookupTable: TAccountMetas[3];
-    userAddressLookupTable: TAccountMetas[4];
-    rent: TAccountMetas[5];
+    payer?: TAccountMetas[1] | undefined;
+    config: TAccountMetas[2];
+    treasury?: TAccountMetas[3] | undefined;
+    systemProgram: TAccountMetas[4];
+    addressLookupTableProgram: TAccountMetas[5];
+    addressLookupTable: TAccountMetas[6];
+    userAddressLookupTable: TAccountMetas[7];
+    rent: TAccountMetas[8];
   };
   data: ExtendAddressLookupTableInstructionData;
 };
@@ -239,7 +276,7 @@ export function parseExtendAddressLookupTableInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedExtendAddressLookupTableInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -249,10 +286,19 @@ export function parseExtendAddressLookupTableInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === LUTS_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       signer: getNextAccount(),
+      payer: getNextOptionalAccount(),
+      config: getNextAccount(),
+      treasury: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
       addressLookupTableProgram: getNextAccount(),
       addressLookupTable: getNextAccount(),
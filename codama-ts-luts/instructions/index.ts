@@ -6,7 +6,33 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./acceptAuthority";
+export * from "./addLutSetMember";
 export * from "./closeAddressLookupTable";
+export * from "./closeOrphanedWrapper";
+export * from "./closePreviousAddressLookupTable";
 export * from "./createAddressLookupTable";
+export * from "./createAddressLookupTableWithNamespace";
+export * from "./createLutSet";
 export * from "./deactivateAddressLookupTable";
 export * from "./extendAddressLookupTable";
+export * from "./extendAddressLookupTableWithArgs";
+export * from "./extendAddressLookupTableWithMode";
+export * from "./extendLutSet";
+export * from "./freezeAddressLookupTable";
+export * from "./getLutStatus";
+export * from "./grantDelegate";
+export * from "./initializeConfig";
+export * from "./initializeLutRegistry";
+export * from "./markInUse";
+export * from "./migrateWrapper";
+export * from "./proposeAuthority";
+export * from "./removeLutSetMember";
+export * from "./resolveAddressIndexes";
+export * from "./revokeDelegate";
+export * from "./rotateAddressLookupTable";
+export * from "./setOwnerAllowlist";
+export * from "./setPaused";
+export * from "./setWrapperMetadata";
+export * from "./syncAddressLookupTable";
+export * from "./updateConfig";
@@ -14,16 +14,46 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
+  type ParsedAcceptAuthorityInstruction,
+  type ParsedAddLutSetMemberInstruction,
   type ParsedCloseAddressLookupTableInstruction,
+  type ParsedCloseOrphanedWrapperInstruction,
+  type ParsedClosePreviousAddressLookupTableInstruction,
   type ParsedCreateAddressLookupTableInstruction,
+  type ParsedCreateAddressLookupTableWithNamespaceInstruction,
+  type ParsedCreateLutSetInstruction,
   type ParsedDeactivateAddressLookupTableInstruction,
   type ParsedExtendAddressLookupTableInstruction,
+  type ParsedExtendAddressLookupTableWithArgsInstruction,
+  type ParsedExtendAddressLookupTableWithModeInstruction,
+  type ParsedExtendLutSetInstruction,
+  type ParsedFreezeAddressLookupTableInstruction,
+  type ParsedGetLutStatusInstruction,
+  type ParsedGrantDelegateInstruction,
+  type ParsedInitializeConfigInstruction,
+  type ParsedInitializeLutRegistryInstruction,
+  type ParsedMarkInUseInstruction,
+  type ParsedMigrateWrapperInstruction,
+  type ParsedProposeAuthorityInstruction,
+  type ParsedRemoveLutSetMemberInstruction,
+  type ParsedResolveAddressIndexesInstruction,
+  type ParsedRevokeDelegateInstruction,
+  type ParsedRotateAddressLookupTableInstruction,
+  type ParsedSetOwnerAllowlistInstruction,
+  type ParsedSetPausedInstruction,
+  type ParsedSetWrapperMetadataInstruction,
+  type ParsedSyncAddressLookupTableInstruction,
+  type ParsedUpdateConfigInstruction,
 } from "../instructions";
 
 export const LUTS_PROGRAM_ADDRESS =
   "846qK5Drj9NEn2P4AvXCKxoVnyYQYGzMu2W7gyvoYjHT" as Address<"846qK5Drj9NEn2P4AvXCKxoVnyYQYGzMu2W7gyvoYjHT">;
 
 export enum LutsAccount {
+  Config,
+  LutCounter,
+  LutRegistry,
+  LutSet,
   UserAddressLookupTable,
 }
 
@@ -31,6 +61,50 @@ export function identifyLutsAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): LutsAccount {
   const data = "data" in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 12, 170, 224, 30, 250, 204, 130])
+      ),
+      0
+    )
+  ) {
+    return LutsAccount.Config;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([229, 116, 218, 120, 63, 135, 182, 81])
+      ),
+      0
+    )
+  ) {
+    return LutsAccount.LutCounter;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([47, 162, 170, 176, 35, 80, 132, 153])
+      ),
+      0
+    )
+  ) {
+    return LutsAccount.LutRegistry;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([128, 2, 188, 112, 106, 104, 145, 226])
+      ),
+      0
+    )
+  ) {
+    return LutsAccount.LutSet;
+  }
   if (
     containsBytes(
       data,
@@ -48,16 +122,64 @@ export function identifyLutsAccount(
 }
 
 export enum LutsInstruction {
+  AcceptAuthority,
+  AddLutSetMember,
   CloseAddressLookupTable,
+  CloseOrphanedWrapper,
+  ClosePreviousAddressLookupTable,
   CreateAddressLookupTable,
+  CreateAddressLookupTableWithNamespace,
+  CreateLutSet,
   DeactivateAddressLookupTable,
   ExtendAddressLookupTable,
+  ExtendAddressLookupTableWithArgs,
+  ExtendAddressLookupTableWithMode,
+  ExtendLutSet,
+  FreezeAddressLookupTable,
+  GetLutStatus,
+  GrantDelegate,
+  InitializeConfig,
+  InitializeLutRegistry,
+  MarkInUse,
+  MigrateWrapper,
+  ProposeAuthority,
+  RemoveLutSetMember,
+  ResolveAddressIndexes,
+  RevokeDelegate,
+  RotateAddressLookupTable,
+  SetOwnerAllowlist,
+  SetPaused,
+  SetWrapperMetadata,
+  SyncAddressLookupTable,
+  UpdateConfig,
 }
 
 export function identifyLutsInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): LutsInstruction {
   const data = "data" in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 86, 198, 91, 33, 12, 107, 160])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.AcceptAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 160, 100, 136, 217, 79, 97, 9])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.AddLutSetMember;
+  }
   if (
     containsBytes(
       data,
@@ -69,6 +191,28 @@ export function identifyLutsInstruction(
   ) {
     return LutsInstruction.CloseAddressLookupTable;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([80, 101, 105, 197, 67, 137, 235, 21])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.CloseOrphanedWrapper;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 94, 46, 160, 154, 49, 129, 188])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.ClosePreviousAddressLookupTable;
+  }
   if (
     containsBytes(
       data,
@@ -80,6 +224,28 @@ export function identifyLutsInstruction(
   ) {
     return LutsInstruction.CreateAddressLookupTable;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([187, 151, 85, 118, 247, 69, 169, 16])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.CreateAddressLookupTableWithNamespace;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([169, 182, 121, 163, 56, 73, 186, 178])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.CreateLutSet;
+  }
   if (
     containsBytes(
       data,
@@ -102,6 +268,226 @@ export function identifyLutsInstruction(
   ) {
     return LutsInstruction.ExtendAddressLookupTable;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([23, 21, 91, 54, 21, 114, 232, 223])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.ExtendAddressLookupTableWithArgs;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([203, 101, 116, 231, 11, 167, 118, 45])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.ExtendAddressLookupTableWithMode;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([138, 249, 88, 75, 78, 253, 193, 197])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.ExtendLutSet;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([218, 63, 109, 75, 104, 3, 210, 47])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.FreezeAddressLookupTable;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([200, 170, 222, 96, 36, 11, 119, 252])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.GetLutStatus;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([250, 169, 110, 217, 41, 160, 97, 184])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.GrantDelegate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 127, 21, 1, 194, 190, 196, 70])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.InitializeConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 116, 78, 124, 97, 23, 226, 109])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.InitializeLutRegistry;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([53, 204, 81, 122, 179, 226, 226, 178])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.MarkInUse;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([16, 95, 70, 20, 31, 47, 225, 19])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.MigrateWrapper;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 148, 236, 198, 76, 119, 99, 142])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.ProposeAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([221, 170, 229, 228, 147, 170, 125, 248])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.RemoveLutSetMember;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([61, 141, 146, 10, 80, 234, 193, 128])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.ResolveAddressIndexes;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([142, 66, 98, 126, 102, 60, 92, 163])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.RevokeDelegate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([39, 92, 143, 109, 231, 147, 248, 74])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.RotateAddressLookupTable;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([192, 251, 15, 84, 59, 137, 188, 2])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.SetOwnerAllowlist;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([91, 60, 125, 192, 176, 225, 166, 218])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.SetPaused;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 112, 169, 172, 149, 206, 35, 86])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.SetWrapperMetadata;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([54, 130, 164, 100, 35, 241, 180, 12])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.SyncAddressLookupTable;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 158, 252, 191, 10, 83, 219, 99])
+      ),
+      0
+    )
+  ) {
+    return LutsInstruction.UpdateConfig;
+  }
   throw new Error(
     "The provided instruction could not be identified as a luts instruction."
   );
@@ -110,15 +496,93 @@ export function identifyLutsInstruction(
 export type ParsedLutsInstruction<
   TProgram extends string = "846qK5Drj9NEn2P4AvXCKxoVnyYQYGzMu2W7gyvoYjHT"
 > =
+  | ({
+      instructionType: LutsInstruction.AcceptAuthority;
+    } & ParsedAcceptAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.AddLutSetMember;
+    } & ParsedAddLutSetMemberInstruction<TProgram>)
   | ({
       instructionType: LutsInstruction.CloseAddressLookupTable;
     } & ParsedCloseAddressLookupTableInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.CloseOrphanedWrapper;
+    } & ParsedCloseOrphanedWrapperInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.ClosePreviousAddressLookupTable;
+    } & ParsedClosePreviousAddressLookupTableInstruction<TProgram>)
   | ({
       instructionType: LutsInstruction.CreateAddressLookupTable;
     } & ParsedCreateAddressLookupTableInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.CreateAddressLookupTableWithNamespace;
+    } & ParsedCreateAddressLookupTableWithNamespaceInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.CreateLutSet;
+    } & ParsedCreateLutSetInstruction<TProgram>)
   | ({
       instructionType: LutsInstruction.DeactivateAddressLookupTable;
     } & ParsedDeactivateAddressLookupTableInstruction<TProgram>)
   | ({
       instructionType: LutsInstruction.ExtendAddressLookupTable;
-    } & ParsedExtendAddressLookupTableInstruction<TProgram>);
+    } & ParsedExtendAddressLookupTableInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.ExtendAddressLookupTableWithArgs;
+    } & ParsedExtendAddressLookupTableWithArgsInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.ExtendAddressLookupTableWithMode;
+    } & ParsedExtendAddressLookupTableWithModeInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.ExtendLutSet;
+    } & ParsedExtendLutSetInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.FreezeAddressLookupTable;
+    } & ParsedFreezeAddressLookupTableInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.GetLutStatus;
+    } & ParsedGetLutStatusInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.GrantDelegate;
+    } & ParsedGrantDelegateInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.InitializeConfig;
+    } & ParsedInitializeConfigInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.InitializeLutRegistry;
+    } & ParsedInitializeLutRegistryInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.MarkInUse;
+    } & ParsedMarkInUseInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.MigrateWrapper;
+    } & ParsedMigrateWrapperInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.ProposeAuthority;
+    } & ParsedProposeAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.RemoveLutSetMember;
+    } & ParsedRemoveLutSetMemberInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.ResolveAddressIndexes;
+    } & ParsedResolveAddressIndexesInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.RevokeDelegate;
+    } & ParsedRevokeDelegateInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.RotateAddressLookupTable;
+    } & ParsedRotateAddressLookupTableInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.SetOwnerAllowlist;
+    } & ParsedSetOwnerAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.SetPaused;
+    } & ParsedSetPausedInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.SetWrapperMetadata;
+    } & ParsedSetWrapperMetadataInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.SyncAddressLookupTable;
+    } & ParsedSyncAddressLookupTableInstruction<TProgram>)
+  | ({
+      instructionType: LutsInstruction.UpdateConfig;
+    } & ParsedUpdateConfigInstruction<TProgram>);
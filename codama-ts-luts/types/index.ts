@@ -6,7 +6,33 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./configUpdated";
+export * from "./extendMode";
+export * from "./extendResult";
+export * from "./lutAuthorityAccepted";
+export * from "./lutAuthorityProposed";
 export * from "./lutClosed";
 export * from "./lutCreated";
 export * from "./lutDeactivated";
+export * from "./lutDelegate";
+export * from "./lutDelegateGranted";
+export * from "./lutDelegateRevoked";
 export * from "./lutExtended";
+export * from "./lutFrozen";
+export * from "./lutMarkedInUse";
+export * from "./lutMetadataSet";
+export * from "./lutRegistryInitialized";
+export * from "./lutRotated";
+export * from "./lutSetCreated";
+export * from "./lutSetExtended";
+export * from "./lutSetMemberAdded";
+export * from "./lutSetMemberRemoved";
+export * from "./lutSetSpill";
+export * from "./lutStatus";
+export * from "./lutStatusInfo";
+export * from "./lutSynced";
+export * from "./lutsPaused";
+export * from "./lutsUnpaused";
+export * from "./orphanedWrapperClosed";
+export * from "./ownerAllowlistSet";
+export * from "./wrapperMigrated";
@@ -7,17 +7,23 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
 } from "@solana/kit";
 
 export type LutCreated = {
@@ -25,6 +31,10 @@ export type LutCreated = {
   lutAddress: Address;
   authority: Address;
   slot: bigint;
+  initialAddresses: number;
+  feeLamports: bigint;
+  label: string;
+  metadataUri: string;
 };
 
 export type LutCreatedArgs = {
@@ -32,29 +42,38 @@ export type LutCreatedArgs = {
   lutAddress: Address;
   authority: Address;
   slot: number | bigint;
+  initialAddresses: number;
+  feeLamports: number | bigint;
+  label: string;
+  metadataUri: string;
 };
 
-export function getLutCreatedEncoder(): FixedSizeEncoder<LutCreatedArgs> {
+export function getLutCreatedEncoder(): Encoder<LutCreatedArgs> {
   return getStructEncoder([
     ["wrapper", getAddressEncoder()],
     ["lutAddress", getAddressEncoder()],
     ["authority", getAddressEncoder()],
     ["slot", getU64Encoder()],
+    ["initialAddresses", getU32Encoder()],
+    ["feeLamports", getU64Encoder()],
+    ["label", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+    ["metadataUri", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
   ]);
 }
 
-export function getLutCreatedDecoder(): FixedSizeDecoder<LutCreated> {
+export function getLutCreatedDecoder(): Decoder<LutCreated> {
   return getStructDecoder([
     ["wrapper", getAddressDecoder()],
     ["lutAddress", getAddressDecoder()],
     ["authority", getAddressDecoder()],
     ["slot", getU64Decoder()],
+    ["initialAddresses", getU32Decoder()],
+    ["feeLamports", getU64Decoder()],
+    ["label", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ["metadataUri", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
   ]);
 }
 
-export function getLutCreatedCodec(): FixedSizeCodec<
-  LutCreatedArgs,
-  LutCreated
-> {
+export function getLutCreatedCodec(): Codec<LutCreatedArgs, LutCreated> {
   return combineCodec(getLutCreatedEncoder(), getLutCreatedDecoder());
 }
@@ -14,6 +14,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -24,15 +26,28 @@ export type LutExtended = {
   wrapper: Address;
   addressesAdded: number;
   totalAddresses: number;
+  duplicatesDropped: number;
+  feeLamports: bigint;
+  addressesRejected: number;
 };
 
-export type LutExtendedArgs = LutExtended;
+export type LutExtendedArgs = {
+  wrapper: Address;
+  addressesAdded: number;
+  totalAddresses: number;
+  duplicatesDropped: number;
+  feeLamports: number | bigint;
+  addressesRejected: number;
+};
 
 export function getLutExtendedEncoder(): FixedSizeEncoder<LutExtendedArgs> {
   return getStructEncoder([
     ["wrapper", getAddressEncoder()],
     ["addressesAdded", getU32Encoder()],
     ["totalAddresses", getU32Encoder()],
+    ["duplicatesDropped", getU32Encoder()],
+    ["feeLamports", getU64Encoder()],
+    ["addressesRejected", getU32Encoder()],
   ]);
 }
 
@@ -41,6 +56,9 @@ export function getLutExtendedDecoder(): FixedSizeDecoder<LutExtended> {
     ["wrapper", getAddressDecoder()],
     ["addressesAdded", getU32Decoder()],
     ["totalAddresses", getU32Decoder()],
+    ["duplicatesDropped", getU32Decoder()],
+    ["feeLamports", getU64Decoder()],
+    ["addressesRejected", getU32Decoder()],
   ]);
 }
 
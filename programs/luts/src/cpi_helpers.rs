@@ -0,0 +1,34 @@
+//! Typed wrappers around the generated `cpi` module for callers that read state back.
+
+use crate::instructions::LutStatusInfo;
+use anchor_lang::prelude::*;
+
+/// Calls `get_lut_status` and decodes the returned `LutStatusInfo`.
+pub fn get_lut_status<'info>(
+    luts_program: AccountInfo<'info>,
+    address_lookup_table: AccountInfo<'info>,
+    user_address_lookup_table: AccountInfo<'info>,
+) -> Result<LutStatusInfo> {
+    let accounts = crate::cpi::accounts::GetLutStatus {
+        address_lookup_table,
+        user_address_lookup_table,
+    };
+    let status = crate::cpi::get_lut_status(CpiContext::new(luts_program, accounts))?;
+    Ok(status.get())
+}
+
+/// Calls `resolve_address_indexes` and decodes the returned indexes.
+pub fn resolve_address_indexes<'info>(
+    luts_program: AccountInfo<'info>,
+    address_lookup_table: AccountInfo<'info>,
+    user_address_lookup_table: AccountInfo<'info>,
+    addresses: Vec<Pubkey>,
+) -> Result<Vec<u16>> {
+    let accounts = crate::cpi::accounts::ResolveAddressIndexes {
+        address_lookup_table,
+        user_address_lookup_table,
+    };
+    let indexes =
+        crate::cpi::resolve_address_indexes(CpiContext::new(luts_program, accounts), addresses)?;
+    Ok(indexes.get())
+}
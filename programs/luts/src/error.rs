@@ -10,4 +10,64 @@ pub enum LutError {
     MaxAddressesExceeded,
     #[msg("No new addresses to add")]
     NoNewAddresses,
+    #[msg("Signer is not the pending authority")]
+    NotPendingAuthority,
+    #[msg("Signer is neither the LUT owner nor an active delegate")]
+    UnauthorizedExtender,
+    #[msg("Maximum delegates exceeded")]
+    MaxDelegatesExceeded,
+    #[msg("Delegate not found")]
+    DelegateNotFound,
+    #[msg("Delegate expiry slot must be in the future")]
+    InvalidDelegateExpiry,
+    #[msg("LUT is frozen and can no longer be modified")]
+    LutFrozen,
+    #[msg("LUT is deactivated")]
+    LutDeactivated,
+    #[msg("LUT is not in burst-fill mode")]
+    NotInBurstFill,
+    #[msg("Invalid config parameters")]
+    InvalidConfig,
+    #[msg("Maximum LUTs per signer exceeded")]
+    MaxLutsPerSignerExceeded,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Signer is not allowed to change the pause state")]
+    NotPauseAuthority,
+    #[msg("Treasury account is required when a fee is charged")]
+    MissingTreasury,
+    #[msg("Treasury does not match the config")]
+    InvalidTreasury,
+    #[msg("The previous LUT must be closed first")]
+    PreviousLutPending,
+    #[msg("Wrapper has no previous LUT")]
+    NoPreviousLut,
+    #[msg("LUT set has no free member slots")]
+    LutSetFull,
+    #[msg("Wrapper is already a member of the LUT set")]
+    LutSetMemberExists,
+    #[msg("Accounts do not match the LUT set members")]
+    InvalidLutSetMember,
+    #[msg("id is below the registry's next_id")]
+    LutIdBelowNext,
+    #[msg("LUT registry is required for a registered LUT")]
+    MissingLutRegistry,
+    #[msg("Native LUT still exists; use close_address_lookup_table")]
+    LutNotOrphaned,
+    #[msg("Wrapper uses the legacy layout; call migrate_wrapper first")]
+    WrapperNeedsMigration,
+    #[msg("Wrapper already uses the current layout")]
+    WrapperAlreadyMigrated,
+    #[msg("Label or metadata URI is too long")]
+    WrapperMetadataTooLong,
+    #[msg("Namespace must not be all zeroes")]
+    InvalidNamespace,
+    #[msg("Address is not owned by an allowed program")]
+    OwnerNotAllowed,
+    #[msg("Maximum allowed owners exceeded")]
+    MaxAllowedOwnersExceeded,
+    #[msg("LUT counter is missing or does not belong to the creator")]
+    MissingLutCounter,
+    #[msg("LUT id is the largest possible id; the registry has no next id")]
+    LutIdOverflow,
 }
@@ -1,3 +1,4 @@
+use crate::state::user_address_lookup_table::LutStatus;
 use anchor_lang::prelude::*;
 
 #[event]
@@ -6,6 +7,10 @@ pub struct LutCreated {
     pub lut_address: Pubkey,
     pub authority: Pubkey,
     pub slot: u64,
+    pub initial_addresses: u32,
+    pub fee_lamports: u64,
+    pub label: String,
+    pub metadata_uri: String,
 }
 
 #[event]
@@ -13,6 +18,9 @@ pub struct LutExtended {
     pub wrapper: Pubkey,
     pub addresses_added: u32,
     pub total_addresses: u32,
+    pub duplicates_dropped: u32,
+    pub fee_lamports: u64,
+    pub addresses_rejected: u32,
 }
 
 #[event]
@@ -26,3 +34,149 @@ pub struct LutClosed {
     pub wrapper: Pubkey,
     pub lut_address: Pubkey,
 }
+
+#[event]
+pub struct LutSynced {
+    pub wrapper: Pubkey,
+    pub size_before: u64,
+    pub size_after: u64,
+    pub status_before: LutStatus,
+    pub status_after: LutStatus,
+    pub deactivated_slot_before: u64,
+    pub deactivated_slot_after: u64,
+    pub last_updated_slot_before: u64,
+    pub last_updated_slot_after: u64,
+    pub ready_at_slot_before: u64,
+    pub ready_at_slot_after: u64,
+}
+
+#[event]
+pub struct OrphanedWrapperClosed {
+    pub wrapper: Pubkey,
+    pub lut_address: Pubkey,
+}
+
+#[event]
+pub struct WrapperMigrated {
+    pub wrapper: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct LutMetadataSet {
+    pub wrapper: Pubkey,
+    pub label: String,
+    pub metadata_uri: String,
+}
+
+#[event]
+pub struct OwnerAllowlistSet {
+    pub wrapper: Pubkey,
+    pub allowed_owners: Vec<Pubkey>,
+}
+
+#[event]
+pub struct LutRotated {
+    pub wrapper: Pubkey,
+    pub previous_lut_address: Pubkey,
+    pub lut_address: Pubkey,
+    pub generation: u64,
+    pub fee_lamports: u64,
+}
+
+#[event]
+pub struct LutAuthorityProposed {
+    pub wrapper: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct LutAuthorityAccepted {
+    pub wrapper: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct LutDelegateGranted {
+    pub wrapper: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at_slot: u64,
+}
+
+#[event]
+pub struct LutDelegateRevoked {
+    pub wrapper: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct LutFrozen {
+    pub wrapper: Pubkey,
+    pub lut_address: Pubkey,
+}
+
+#[event]
+pub struct LutMarkedInUse {
+    pub wrapper: Pubkey,
+    pub ready_at_slot: u64,
+}
+
+#[event]
+pub struct LutSetCreated {
+    pub lut_set: Pubkey,
+    pub authority: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct LutSetMemberAdded {
+    pub lut_set: Pubkey,
+    pub wrapper: Pubkey,
+    pub index: u8,
+}
+
+#[event]
+pub struct LutSetMemberRemoved {
+    pub lut_set: Pubkey,
+    pub wrapper: Pubkey,
+    pub index: u8,
+}
+
+#[event]
+pub struct LutSetExtended {
+    pub lut_set: Pubkey,
+    pub addresses_added: u32,
+    pub duplicates_dropped: u32,
+    pub members_written: u8,
+}
+
+#[event]
+pub struct LutRegistryInitialized {
+    pub lut_registry: Pubkey,
+    pub authority: Pubkey,
+    pub next_id: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub guardian: Pubkey,
+    pub cooldown_slots: u64,
+    pub max_luts_per_signer: u64,
+    pub paused: bool,
+    pub treasury: Pubkey,
+    pub create_fee_lamports: u64,
+    pub extend_fee_lamports_per_address: u64,
+}
+
+#[event]
+pub struct LutsPaused {
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct LutsUnpaused {
+    pub authority: Pubkey,
+}
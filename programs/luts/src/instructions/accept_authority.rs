@@ -0,0 +1,37 @@
+use crate::error::LutError;
+use crate::events::LutAuthorityAccepted;
+use crate::state::user_address_lookup_table::{LutDelegate, UserAddressLookupTable};
+use anchor_lang::prelude::*;
+
+/// Accepts a pending authority transfer for a wrapped Address Lookup Table.
+///
+/// The signer must be the `pending_authority` recorded by `propose_authority`. The
+/// wrapper keeps its address and native LUT; only the effective authority changes.
+/// Delegates granted by the previous authority are cleared.
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = user_address_lookup_table.has_pending_authority() @ LutError::NotPendingAuthority,
+        constraint = user_address_lookup_table.pending_authority == signer.key() @ LutError::NotPendingAuthority,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let previous_authority = user_address_lookup_table.signer;
+    user_address_lookup_table.signer = ctx.accounts.signer.key();
+    user_address_lookup_table.pending_authority = Pubkey::default();
+    user_address_lookup_table.delegates =
+        [LutDelegate::default(); UserAddressLookupTable::MAX_DELEGATES];
+    emit!(LutAuthorityAccepted {
+        wrapper: user_address_lookup_table.key(),
+        previous_authority,
+        new_authority: user_address_lookup_table.signer,
+    });
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::error::LutError;
+use crate::events::LutSetMemberAdded;
+use crate::state::lut_set::LutSet;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Appends an existing wrapper to the end of a LUT set's fill order.
+///
+/// The wrapper must be owned by the set's owner.
+#[derive(Accounts)]
+pub struct AddLutSetMember<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        constraint = !lut_set.is_full() @ LutError::LutSetFull,
+        constraint = !lut_set.contains(&user_address_lookup_table.key()) @ LutError::LutSetMemberExists,
+        seeds = [LutSet::SEED.as_bytes(), lut_set.signer.as_ref(), lut_set.id.to_le_bytes().as_ref()],
+        bump = lut_set.bump
+    )]
+    pub lut_set: Box<Account<'info, LutSet>>,
+    #[account(
+        has_one = signer,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn add_lut_set_member(ctx: Context<AddLutSetMember>) -> Result<()> {
+    let lut_set = &mut ctx.accounts.lut_set;
+    let wrapper = ctx.accounts.user_address_lookup_table.key();
+    let index = lut_set.push(wrapper);
+    emit!(LutSetMemberAdded {
+        lut_set: lut_set.key(),
+        wrapper,
+        index,
+    });
+    Ok(())
+}
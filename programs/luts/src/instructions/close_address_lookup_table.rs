@@ -1,5 +1,8 @@
 use crate::constants::LutProgram;
+use crate::error::LutError;
 use crate::events::LutClosed;
+use crate::state::lut_counter::LutCounter;
+use crate::state::lut_registry::LutRegistry;
 use crate::state::user_address_lookup_table::UserAddressLookupTable;
 use anchor_lang::prelude::*;
 use solana_address_lookup_table_interface::instruction::close_lookup_table;
@@ -7,11 +10,18 @@ use solana_address_lookup_table_interface::instruction::close_lookup_table;
 /// Closes a deactivated Address Lookup Table and its wrapper account.
 ///
 /// The LUT must be deactivated and no longer referenced by recent transactions.
-/// Rent from both the native LUT and the wrapper PDA is returned to the signer.
+/// Rent from both the native LUT and the wrapper PDA is returned to the optional
+/// `rent_destination`, falling back to the signer. A LUT retired by rotation must be
+/// closed with `close_previous_address_lookup_table` first. Registered wrappers also
+/// require the creator's `lut_registry`, which has the id pruned from its live list, and
+/// counted wrappers require the creator's `lut_counter`, which is decremented.
 #[derive(Accounts)]
 pub struct CloseAddressLookupTable<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
+    /// CHECK: Only receives lamports
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub address_lookup_table_program: Program<'info, LutProgram>,
     /// CHECK: Validated via has_one constraint on wrapper
@@ -21,17 +31,48 @@ pub struct CloseAddressLookupTable<'info> {
         mut,
         has_one = address_lookup_table,
         has_one = signer,
-        seeds = [UserAddressLookupTable::SEED.as_bytes(), signer.key().as_ref(), user_address_lookup_table.id.to_le_bytes().as_ref()],
-        bump = user_address_lookup_table.bump,
-        close = signer
+        constraint = !user_address_lookup_table.has_previous_lookup_table() @ LutError::PreviousLutPending,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
     )]
     pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+    #[account(
+        mut,
+        seeds = [LutRegistry::SEED.as_bytes(), user_address_lookup_table.creator.as_ref()],
+        bump = lut_registry.bump
+    )]
+    pub lut_registry: Option<Box<Account<'info, LutRegistry>>>,
+    #[account(
+        mut,
+        seeds = [LutCounter::SEED.as_bytes(), user_address_lookup_table.creator.as_ref()],
+        bump = lut_counter.bump
+    )]
+    pub lut_counter: Option<Box<Account<'info, LutCounter>>>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn close_address_lookup_table(ctx: Context<CloseAddressLookupTable>) -> Result<()> {
-    let signer = &ctx.accounts.signer;
+    let rent_destination = match &ctx.accounts.rent_destination {
+        Some(rent_destination) => rent_destination.to_account_info(),
+        None => ctx.accounts.signer.to_account_info(),
+    };
     let user_address_lookup_table = &ctx.accounts.user_address_lookup_table;
+    if user_address_lookup_table.registered {
+        let lut_registry = ctx
+            .accounts
+            .lut_registry
+            .as_mut()
+            .ok_or(LutError::MissingLutRegistry)?;
+        lut_registry.release(user_address_lookup_table.id);
+    }
+    if user_address_lookup_table.counted {
+        let lut_counter = ctx
+            .accounts
+            .lut_counter
+            .as_mut()
+            .ok_or(LutError::MissingLutCounter)?;
+        lut_counter.live_luts = lut_counter.live_luts.saturating_sub(1);
+    }
     let address_lookup_table = &ctx.accounts.address_lookup_table;
     let seeds = user_address_lookup_table.seeds();
     let seed_slices: Vec<&[u8]> = seeds.iter().map(|v| v.as_slice()).collect();
@@ -39,18 +80,19 @@ pub fn close_address_lookup_table(ctx: Context<CloseAddressLookupTable>) -> Resu
     let ix = close_lookup_table(
         address_lookup_table.key(),
         user_address_lookup_table.key(),
-        signer.key(),
+        rent_destination.key(),
     );
     program::invoke_signed(
         &ix,
         &[
-            signer.to_account_info(),
+            rent_destination.clone(),
             ctx.accounts.system_program.to_account_info(),
             address_lookup_table.to_account_info(),
             user_address_lookup_table.to_account_info(),
         ],
         signer_seeds,
     )?;
+    user_address_lookup_table.close(rent_destination)?;
     emit!(LutClosed {
         wrapper: user_address_lookup_table.key(),
         lut_address: address_lookup_table.key(),
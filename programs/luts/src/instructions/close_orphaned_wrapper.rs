@@ -0,0 +1,75 @@
+use crate::constants::LOOKUP_TABLE_PROGRAM;
+use crate::error::LutError;
+use crate::events::OrphanedWrapperClosed;
+use crate::state::lut_counter::LutCounter;
+use crate::state::lut_registry::LutRegistry;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Closes a wrapper whose native Address Lookup Table no longer exists.
+///
+/// For wrappers left behind when the native LUT was closed or never created, which
+/// `close_address_lookup_table` can't close because it CPIs into the ALT program. The
+/// native account must be empty or not owned by the ALT program. Only the wrapper is
+/// closed, with its rent returned to the signer. A LUT retired by rotation must still be
+/// closed with `close_previous_address_lookup_table` first. Registered wrappers require
+/// the creator's `lut_registry` so the id can be pruned, and counted wrappers the
+/// creator's `lut_counter` so it can be decremented.
+#[derive(Accounts)]
+pub struct CloseOrphanedWrapper<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: Validated via has_one constraint on wrapper; must not be a live LUT
+    #[account(
+        constraint = address_lookup_table.data_is_empty()
+            || *address_lookup_table.owner != LOOKUP_TABLE_PROGRAM @ LutError::LutNotOrphaned
+    )]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = signer,
+        has_one = address_lookup_table,
+        has_one = signer,
+        constraint = !user_address_lookup_table.has_previous_lookup_table() @ LutError::PreviousLutPending,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+    #[account(
+        mut,
+        seeds = [LutRegistry::SEED.as_bytes(), user_address_lookup_table.creator.as_ref()],
+        bump = lut_registry.bump
+    )]
+    pub lut_registry: Option<Box<Account<'info, LutRegistry>>>,
+    #[account(
+        mut,
+        seeds = [LutCounter::SEED.as_bytes(), user_address_lookup_table.creator.as_ref()],
+        bump = lut_counter.bump
+    )]
+    pub lut_counter: Option<Box<Account<'info, LutCounter>>>,
+}
+
+pub fn close_orphaned_wrapper(ctx: Context<CloseOrphanedWrapper>) -> Result<()> {
+    let user_address_lookup_table = &ctx.accounts.user_address_lookup_table;
+    if user_address_lookup_table.registered {
+        let lut_registry = ctx
+            .accounts
+            .lut_registry
+            .as_mut()
+            .ok_or(LutError::MissingLutRegistry)?;
+        lut_registry.release(user_address_lookup_table.id);
+    }
+    if user_address_lookup_table.counted {
+        let lut_counter = ctx
+            .accounts
+            .lut_counter
+            .as_mut()
+            .ok_or(LutError::MissingLutCounter)?;
+        lut_counter.live_luts = lut_counter.live_luts.saturating_sub(1);
+    }
+    emit!(OrphanedWrapperClosed {
+        wrapper: user_address_lookup_table.key(),
+        lut_address: ctx.accounts.address_lookup_table.key(),
+    });
+    Ok(())
+}
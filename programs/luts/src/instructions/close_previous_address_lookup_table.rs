@@ -0,0 +1,68 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::events::LutClosed;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::instruction::close_lookup_table;
+
+/// Closes the native LUT retired by `rotate_address_lookup_table`.
+///
+/// The retired LUT was deactivated during rotation and can be closed once the native
+/// cooldown has passed. Its rent is returned to the optional `rent_destination`, falling
+/// back to the signer. The wrapper stays open and keeps its current LUT.
+#[derive(Accounts)]
+pub struct ClosePreviousAddressLookupTable<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: Only receives lamports
+    #[account(mut)]
+    pub rent_destination: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    /// CHECK: Validated via has_one constraint on wrapper
+    #[account(mut)]
+    pub previous_address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        constraint = user_address_lookup_table.has_previous_lookup_table() @ LutError::NoPreviousLut,
+        has_one = previous_address_lookup_table,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn close_previous_address_lookup_table(
+    ctx: Context<ClosePreviousAddressLookupTable>,
+) -> Result<()> {
+    let rent_destination = match &ctx.accounts.rent_destination {
+        Some(rent_destination) => rent_destination.to_account_info(),
+        None => ctx.accounts.signer.to_account_info(),
+    };
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let previous_address_lookup_table = &ctx.accounts.previous_address_lookup_table;
+    let seeds = user_address_lookup_table.seeds();
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|v| v.as_slice()).collect();
+    let signer_seeds: &[&[&[u8]]] = &[seed_slices.as_slice()];
+    let ix = close_lookup_table(
+        previous_address_lookup_table.key(),
+        user_address_lookup_table.key(),
+        rent_destination.key(),
+    );
+    program::invoke_signed(
+        &ix,
+        &[
+            rent_destination.clone(),
+            previous_address_lookup_table.to_account_info(),
+            user_address_lookup_table.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+    user_address_lookup_table.previous_address_lookup_table = Pubkey::default();
+    emit!(LutClosed {
+        wrapper: user_address_lookup_table.key(),
+        lut_address: previous_address_lookup_table.key(),
+    });
+    Ok(())
+}
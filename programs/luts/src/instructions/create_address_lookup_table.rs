@@ -1,9 +1,18 @@
 use crate::constants::LutProgram;
 use crate::error::LutError;
 use crate::events::LutCreated;
-use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use crate::instructions::collect_fee;
+use crate::state::config::Config;
+use crate::state::lut_counter::LutCounter;
+use crate::state::lut_registry::LutRegistry;
+use crate::state::user_address_lookup_table::{LutDelegate, LutStatus, UserAddressLookupTable};
 use anchor_lang::prelude::*;
-use solana_address_lookup_table_interface::instruction::create_lookup_table;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, extend_lookup_table,
+};
 
 /// Arguments for creating a new Address Lookup Table.
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -12,31 +21,70 @@ pub struct CreateAddressLookupTableArgs {
     pub recent_slot: u64,
     /// User-defined identifier allowing multiple LUTs per signer.
     pub id: u64,
+    /// Addresses written to the LUT in the same instruction. Duplicates are dropped.
+    pub initial_addresses: Option<Vec<Pubkey>>,
+    /// Start in burst-fill mode, allowing back-to-back extends until `mark_in_use`.
+    pub burst_fill: Option<bool>,
+    /// Optional name for the wrapper, at most `UserAddressLookupTable::MAX_LABEL_LEN` bytes.
+    pub label: Option<String>,
+    /// Optional link to off-chain metadata, at most `UserAddressLookupTable::MAX_METADATA_URI_LEN` bytes.
+    pub metadata_uri: Option<String>,
 }
 
 /// Creates a new Address Lookup Table with an associated wrapper PDA.
 ///
 /// The wrapper PDA becomes the authority of the native LUT, allowing this program
 /// to manage extensions and lifecycle. The LUT address is deterministically derived
-/// from the wrapper PDA and the recent_slot.
+/// from the wrapper PDA and the recent_slot. Rent for both accounts is funded by the
+/// optional `payer`, falling back to the signer when it is omitted. Any `initial_addresses`
+/// are deduplicated and written in the same instruction, so the LUT does not have to wait
+/// out the extend cooldown before being populated. The payer is charged the configured
+/// create fee plus the per-address fee for each initial address, paid to the treasury.
+/// The signer's `lut_counter` is created on their first create and counts their live
+/// wrappers, which must stay within the config's `max_luts_per_signer`. When the signer's
+/// `lut_registry` is passed, `id` must be at least its `next_id` and is recorded as live; an
+/// id already taken by an unregistered create is skipped by passing the one after it.
 #[derive(Accounts)]
 #[instruction(args: CreateAddressLookupTableArgs)]
 pub struct CreateAddressLookupTable<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ LutError::ProgramPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub address_lookup_table_program: Program<'info, LutProgram>,
     /// CHECK: Validated inside instruction
     #[account(mut)]
     pub address_lookup_table: UncheckedAccount<'info>,
     #[account(
-        init,
-        payer = signer,
+        mut,
+        seeds = [LutRegistry::SEED.as_bytes(), signer.key().as_ref()],
+        bump = lut_registry.bump
+    )]
+    pub lut_registry: Option<Box<Account<'info, LutRegistry>>>,
+    /// CHECK: Initialized inside instruction on the signer's first create
+    #[account(
+        mut,
+        seeds = [LutCounter::SEED.as_bytes(), signer.key().as_ref()],
+        bump
+    )]
+    pub lut_counter: UncheckedAccount<'info>,
+    /// CHECK: Initialized inside instruction so that the optional payer can fund it
+    #[account(
+        mut,
         seeds = [UserAddressLookupTable::SEED.as_bytes(), signer.key().as_ref(), args.id.to_le_bytes().as_ref()],
-        space = UserAddressLookupTable::SIZE,
         bump
     )]
-    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+    pub user_address_lookup_table: UncheckedAccount<'info>,
     pub rent: Sysvar<'info, Rent>,
 }
 
@@ -44,19 +92,121 @@ pub fn create_address_lookup_table(
     ctx: Context<CreateAddressLookupTable>,
     args: CreateAddressLookupTableArgs,
 ) -> Result<()> {
-    let signer = &ctx.accounts.signer;
-    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
-    let address_lookup_table = &ctx.accounts.address_lookup_table;
-    user_address_lookup_table.bump = ctx.bumps.user_address_lookup_table;
-    user_address_lookup_table.id = args.id;
-    user_address_lookup_table.signer = signer.key();
-    user_address_lookup_table.address_lookup_table = address_lookup_table.key();
-    user_address_lookup_table.size = 0;
+    let payer = match &ctx.accounts.payer {
+        Some(payer) => payer.to_account_info(),
+        None => ctx.accounts.signer.to_account_info(),
+    };
+    create_wrapped_lookup_table(
+        &ctx.accounts.signer,
+        &payer,
+        &ctx.accounts.config,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.address_lookup_table_program,
+        &ctx.accounts.address_lookup_table,
+        &ctx.accounts.user_address_lookup_table,
+        &ctx.accounts.rent,
+        ctx.bumps.user_address_lookup_table,
+        ctx.accounts.lut_registry.as_deref_mut(),
+        &ctx.accounts.lut_counter,
+        ctx.bumps.lut_counter,
+        [0; 32],
+        args,
+    )
+}
+
+/// Shared create path: initializes the wrapper PDA at `bump`, creates the native LUT with
+/// the wrapper as its authority, writes any initial addresses and collects the create fee.
+/// The wrapper is counted in `lut_counter`, the signer's counter PDA at `lut_counter_bump`,
+/// against `max_luts_per_signer`. If `lut_registry` is given, `args.id` must be at least its
+/// next id, and is then recorded as live. A non-zero `namespace` is stored on the wrapper
+/// and replaces `args.id` in its seeds. The caller is responsible for checking that
+/// `user_address_lookup_table` is the PDA for the signer and `args.id` or `namespace`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_wrapped_lookup_table<'info>(
+    signer: &Signer<'info>,
+    payer: &AccountInfo<'info>,
+    config: &Config,
+    treasury: Option<&UncheckedAccount<'info>>,
+    system_program: &Program<'info, System>,
+    address_lookup_table_program: &Program<'info, LutProgram>,
+    address_lookup_table: &UncheckedAccount<'info>,
+    user_address_lookup_table: &UncheckedAccount<'info>,
+    rent: &Sysvar<'info, Rent>,
+    bump: u8,
+    lut_registry: Option<&mut Account<'info, LutRegistry>>,
+    lut_counter: &UncheckedAccount<'info>,
+    lut_counter_bump: u8,
+    namespace: [u8; 32],
+    args: CreateAddressLookupTableArgs,
+) -> Result<()> {
+    count_lut(
+        signer,
+        payer,
+        config,
+        system_program,
+        rent,
+        lut_counter,
+        lut_counter_bump,
+    )?;
+    let registered = lut_registry.is_some();
+    if let Some(lut_registry) = lut_registry {
+        require!(args.id >= lut_registry.next_id, LutError::LutIdBelowNext);
+        lut_registry.allocate(args.id)?;
+        grow_lut_registry(payer, system_program, rent, lut_registry)?;
+    }
     let clock = Clock::get()?;
-    user_address_lookup_table.last_updated_slot = clock.slot;
+    let mut initial_addresses: Vec<Pubkey> = Vec::new();
+    for addr in args.initial_addresses.unwrap_or_default() {
+        if !initial_addresses.contains(&addr) {
+            initial_addresses.push(addr);
+        }
+    }
+    require!(
+        initial_addresses.len() <= UserAddressLookupTable::MAX_ADDRESSES,
+        LutError::MaxAddressesExceeded
+    );
+    let mut wrapper = UserAddressLookupTable {
+        bump,
+        signer: signer.key(),
+        size: initial_addresses.len() as u64,
+        id: args.id,
+        address_lookup_table: address_lookup_table.key(),
+        last_updated_slot: clock.slot,
+        creator: signer.key(),
+        pending_authority: Pubkey::default(),
+        delegates: [LutDelegate::default(); UserAddressLookupTable::MAX_DELEGATES],
+        status: LutStatus::Active,
+        deactivated_slot: 0,
+        burst_fill: args.burst_fill.unwrap_or(false),
+        ready_at_slot: clock.slot.saturating_add(config.cooldown_slots),
+        previous_address_lookup_table: Pubkey::default(),
+        generation: 0,
+        registered,
+        version: UserAddressLookupTable::CURRENT_VERSION,
+        label: String::new(),
+        metadata_uri: String::new(),
+        namespace,
+        allowed_owners: [Pubkey::default(); UserAddressLookupTable::MAX_ALLOWED_OWNERS],
+        allowed_owner_count: 0,
+        counted: true,
+    };
+    wrapper.set_metadata(args.label, args.metadata_uri)?;
+    let seeds = wrapper.seeds();
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|v| v.as_slice()).collect();
+    let signer_seeds: &[&[&[u8]]] = &[seed_slices.as_slice()];
+    init_program_account(
+        payer,
+        user_address_lookup_table,
+        system_program,
+        rent,
+        UserAddressLookupTable::SIZE,
+        signer_seeds,
+    )?;
+    wrapper.try_serialize(&mut &mut user_address_lookup_table.try_borrow_mut_data()?[..])?;
     let (ix, address) = create_lookup_table(
         user_address_lookup_table.key(),
-        signer.key(),
+        payer.key(),
         args.recent_slot,
     );
     require_keys_eq!(
@@ -67,18 +217,179 @@ pub fn create_address_lookup_table(
     program::invoke(
         &ix,
         &[
-            signer.to_account_info(),
+            payer.clone(),
             address_lookup_table.to_account_info(),
             user_address_lookup_table.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.address_lookup_table_program.to_account_info(),
+            system_program.to_account_info(),
+            address_lookup_table_program.to_account_info(),
         ],
     )?;
+    let initial_count = initial_addresses.len() as u32;
+    if !initial_addresses.is_empty() {
+        let ix = extend_lookup_table(
+            address_lookup_table.key(),
+            user_address_lookup_table.key(),
+            Some(payer.key()),
+            initial_addresses,
+        );
+        program::invoke_signed(
+            &ix,
+            &[
+                payer.clone(),
+                system_program.to_account_info(),
+                address_lookup_table.to_account_info(),
+                user_address_lookup_table.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+    }
+    let fee_lamports = config
+        .create_fee_lamports
+        .saturating_add(config.extend_fee(initial_count as usize));
+    collect_fee(payer, treasury, system_program, fee_lamports)?;
     emit!(LutCreated {
         wrapper: user_address_lookup_table.key(),
         lut_address: address_lookup_table.key(),
         authority: signer.key(),
         slot: clock.slot,
+        initial_addresses: initial_count,
+        fee_lamports,
+        label: wrapper.label,
+        metadata_uri: wrapper.metadata_uri,
     });
     Ok(())
 }
+
+/// Adds a wrapper to the signer's live count, creating the counter on first use. Fails if
+/// the count is already at the config's `max_luts_per_signer`.
+fn count_lut<'info>(
+    signer: &Signer<'info>,
+    payer: &AccountInfo<'info>,
+    config: &Config,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    lut_counter: &UncheckedAccount<'info>,
+    bump: u8,
+) -> Result<()> {
+    let mut counter = if *lut_counter.owner == crate::ID {
+        LutCounter::try_deserialize(&mut &lut_counter.try_borrow_data()?[..])?
+    } else {
+        let signer_key = signer.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[LutCounter::SEED.as_bytes(), signer_key.as_ref(), &[bump]]];
+        init_program_account(
+            payer,
+            lut_counter,
+            system_program,
+            rent,
+            LutCounter::SIZE,
+            signer_seeds,
+        )?;
+        LutCounter {
+            bump,
+            signer: signer_key,
+            live_luts: 0,
+        }
+    };
+    require!(
+        config.allows_another_lut(counter.live_luts),
+        LutError::MaxLutsPerSignerExceeded
+    );
+    counter.live_luts += 1;
+    counter.try_serialize(&mut &mut lut_counter.try_borrow_mut_data()?[..])
+}
+
+/// Grows the registry account to fit its live ids, with the payer funding the extra rent.
+fn grow_lut_registry<'info>(
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    lut_registry: &Account<'info, LutRegistry>,
+) -> Result<()> {
+    let registry_info = lut_registry.to_account_info();
+    let space = LutRegistry::space(lut_registry.live_ids.len());
+    if registry_info.data_len() >= space {
+        return Ok(());
+    }
+    let top_up = rent
+        .minimum_balance(space)
+        .saturating_sub(registry_info.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: registry_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    registry_info.resize(space)?;
+    Ok(())
+}
+
+/// Allocates a PDA with `space` bytes and assigns it to this program, mirroring what
+/// Anchor's `init` constraint does (including the pre-funded account case) with a dynamic
+/// payer.
+fn init_program_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let account_info = account.to_account_info();
+    let required_lamports = rent.minimum_balance(space);
+    let current_lamports = account_info.lamports();
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account_info,
+                },
+                signer_seeds,
+            ),
+            required_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    let top_up = required_lamports.saturating_sub(current_lamports);
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: account_info.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: account_info,
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
@@ -0,0 +1,106 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::instructions::{create_wrapped_lookup_table, CreateAddressLookupTableArgs};
+use crate::state::config::Config;
+use crate::state::lut_counter::LutCounter;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Arguments for creating a namespaced Address Lookup Table.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAddressLookupTableWithNamespaceArgs {
+    /// A recent slot used to derive the LUT address (must be within ~150 slots of current).
+    pub recent_slot: u64,
+    /// Seed used in place of `id`, typically a hash of a purpose such as "swap-router-v2".
+    /// Must not be all zeroes.
+    pub namespace: [u8; 32],
+    /// Addresses written to the LUT in the same instruction. Duplicates are dropped.
+    pub initial_addresses: Option<Vec<Pubkey>>,
+    /// Start in burst-fill mode, allowing back-to-back extends until `mark_in_use`.
+    pub burst_fill: Option<bool>,
+    /// Optional name for the wrapper, at most `UserAddressLookupTable::MAX_LABEL_LEN` bytes.
+    pub label: Option<String>,
+    /// Optional link to off-chain metadata, at most `UserAddressLookupTable::MAX_METADATA_URI_LEN` bytes.
+    pub metadata_uri: Option<String>,
+}
+
+/// Creates a new Address Lookup Table whose wrapper PDA is derived from a namespace
+/// instead of a numeric id.
+///
+/// The wrapper lives at `["UserAddressLookupTable", signer, namespace]`, so the table for a
+/// given purpose can be found without keeping an id mapping. Namespaced wrappers have
+/// `id` 0 and are not tracked by a `LutRegistry`, but count towards `max_luts_per_signer`
+/// like any other wrapper. Otherwise this behaves like `create_address_lookup_table`, and
+/// every other instruction accepts either kind of wrapper.
+#[derive(Accounts)]
+#[instruction(args: CreateAddressLookupTableWithNamespaceArgs)]
+pub struct CreateAddressLookupTableWithNamespace<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ LutError::ProgramPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    /// CHECK: Validated inside instruction
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    /// CHECK: Initialized inside instruction on the signer's first create
+    #[account(
+        mut,
+        seeds = [LutCounter::SEED.as_bytes(), signer.key().as_ref()],
+        bump
+    )]
+    pub lut_counter: UncheckedAccount<'info>,
+    /// CHECK: Initialized inside instruction so that the optional payer can fund it
+    #[account(
+        mut,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), signer.key().as_ref(), args.namespace.as_ref()],
+        bump
+    )]
+    pub user_address_lookup_table: UncheckedAccount<'info>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_address_lookup_table_with_namespace(
+    ctx: Context<CreateAddressLookupTableWithNamespace>,
+    args: CreateAddressLookupTableWithNamespaceArgs,
+) -> Result<()> {
+    require!(args.namespace != [0; 32], LutError::InvalidNamespace);
+    let payer = match &ctx.accounts.payer {
+        Some(payer) => payer.to_account_info(),
+        None => ctx.accounts.signer.to_account_info(),
+    };
+    create_wrapped_lookup_table(
+        &ctx.accounts.signer,
+        &payer,
+        &ctx.accounts.config,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.address_lookup_table_program,
+        &ctx.accounts.address_lookup_table,
+        &ctx.accounts.user_address_lookup_table,
+        &ctx.accounts.rent,
+        ctx.bumps.user_address_lookup_table,
+        None,
+        &ctx.accounts.lut_counter,
+        ctx.bumps.lut_counter,
+        args.namespace,
+        CreateAddressLookupTableArgs {
+            recent_slot: args.recent_slot,
+            id: 0,
+            initial_addresses: args.initial_addresses,
+            burst_fill: args.burst_fill,
+            label: args.label,
+            metadata_uri: args.metadata_uri,
+        },
+    )
+}
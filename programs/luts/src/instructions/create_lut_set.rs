@@ -0,0 +1,38 @@
+use crate::events::LutSetCreated;
+use crate::state::lut_set::LutSet;
+use anchor_lang::prelude::*;
+
+/// Creates an empty LUT set owned by the signer.
+///
+/// Members are added with `add_lut_set_member`, or created on demand when
+/// `extend_lut_set` spills past the last member.
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateLutSet<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = signer,
+        space = LutSet::SIZE,
+        seeds = [LutSet::SEED.as_bytes(), signer.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub lut_set: Box<Account<'info, LutSet>>,
+}
+
+pub fn create_lut_set(ctx: Context<CreateLutSet>, id: u64) -> Result<()> {
+    let lut_set = &mut ctx.accounts.lut_set;
+    lut_set.bump = ctx.bumps.lut_set;
+    lut_set.signer = ctx.accounts.signer.key();
+    lut_set.id = id;
+    lut_set.member_count = 0;
+    lut_set.members = [Pubkey::default(); LutSet::MAX_MEMBERS];
+    emit!(LutSetCreated {
+        lut_set: lut_set.key(),
+        authority: lut_set.signer,
+        id,
+    });
+    Ok(())
+}
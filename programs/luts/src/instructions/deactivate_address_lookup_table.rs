@@ -1,6 +1,7 @@
 use crate::constants::LutProgram;
+use crate::error::LutError;
 use crate::events::LutDeactivated;
-use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use crate::state::user_address_lookup_table::{LutStatus, UserAddressLookupTable};
 use anchor_lang::prelude::*;
 use solana_address_lookup_table_interface::instruction::deactivate_lookup_table;
 
@@ -8,6 +9,8 @@ use solana_address_lookup_table_interface::instruction::deactivate_lookup_table;
 ///
 /// After deactivation, the LUT enters a cooldown period during which it cannot be used
 /// in new transactions. Once no recent transactions reference it, the LUT can be closed.
+/// Legacy wrappers can be deactivated without `migrate_wrapper`, so their rent can always
+/// be reclaimed; their status is not recorded until they are migrated and synced.
 #[derive(Accounts)]
 pub struct DeactivateAddressLookupTable<'info> {
     #[account(mut)]
@@ -21,7 +24,9 @@ pub struct DeactivateAddressLookupTable<'info> {
         mut,
         has_one = address_lookup_table,
         has_one = signer,
-        seeds = [UserAddressLookupTable::SEED.as_bytes(), signer.key().as_ref(), user_address_lookup_table.id.to_le_bytes().as_ref()],
+        constraint = !user_address_lookup_table.is_frozen() @ LutError::LutFrozen,
+        constraint = !user_address_lookup_table.is_deactivated() @ LutError::LutDeactivated,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
         bump = user_address_lookup_table.bump
     )]
     pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
@@ -30,7 +35,7 @@ pub struct DeactivateAddressLookupTable<'info> {
 
 pub fn deactivate_address_lookup_table(ctx: Context<DeactivateAddressLookupTable>) -> Result<()> {
     let signer = &ctx.accounts.signer;
-    let user_address_lookup_table = &ctx.accounts.user_address_lookup_table;
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
     let address_lookup_table = &ctx.accounts.address_lookup_table;
     let seeds = user_address_lookup_table.seeds();
     let seed_slices: Vec<&[u8]> = seeds.iter().map(|v| v.as_slice()).collect();
@@ -46,6 +51,12 @@ pub fn deactivate_address_lookup_table(ctx: Context<DeactivateAddressLookupTable
         ],
         signer_seeds,
     )?;
+    // The legacy layout has no room for the status, so legacy wrappers are left as they
+    // are; the native table records the deactivation and close only relies on that.
+    if user_address_lookup_table.is_current() {
+        user_address_lookup_table.status = LutStatus::Deactivated;
+        user_address_lookup_table.deactivated_slot = Clock::get()?.slot;
+    }
     emit!(LutDeactivated {
         wrapper: user_address_lookup_table.key(),
         lut_address: address_lookup_table.key(),
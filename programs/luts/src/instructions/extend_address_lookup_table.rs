@@ -1,21 +1,41 @@
 use crate::constants::LutProgram;
 use crate::error::LutError;
 use crate::events::LutExtended;
+use crate::instructions::{ExtendMode, ExtendResult};
+use crate::state::config::Config;
 use crate::state::user_address_lookup_table::UserAddressLookupTable;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program;
+use anchor_lang::system_program::{transfer, Transfer};
 use solana_address_lookup_table_interface::instruction::extend_lookup_table;
 use solana_address_lookup_table_interface::state::AddressLookupTable;
 
 /// Extends an Address Lookup Table with new addresses.
 ///
 /// Addresses to add are passed via remaining_accounts. The instruction automatically
-/// deduplicates against existing entries in the LUT. A cooldown period must have passed
-/// since the last update, and the total cannot exceed 256 addresses.
+/// deduplicates against existing entries in the LUT and within the batch itself, keeping
+/// the first occurrence of each address. The configured cooldown must have passed since
+/// the last update unless the LUT is still in burst-fill mode, and the total cannot
+/// exceed 256 addresses. Reallocation of the native LUT is funded by the optional
+/// `payer`, falling back to the signer. The signer may be the owner or one of the
+/// wrapper's active delegates. When the config sets a per-address fee, the payer also
+/// pays it to the treasury for each address written. When the wrapper has an owner
+/// allowlist, every remaining account must be owned by one of the listed programs.
 #[derive(Accounts)]
 pub struct ExtendAddressLookupTable<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ LutError::ProgramPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
     pub system_program: Program<'info, System>,
     pub address_lookup_table_program: Program<'info, LutProgram>,
     /// CHECK: Validated via has_one constraint on wrapper
@@ -24,8 +44,7 @@ pub struct ExtendAddressLookupTable<'info> {
     #[account(
         mut,
         has_one = address_lookup_table,
-        has_one = signer,
-        seeds = [UserAddressLookupTable::SEED.as_bytes(), signer.key().as_ref(), user_address_lookup_table.id.to_le_bytes().as_ref()],
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
         bump = user_address_lookup_table.bump
     )]
     pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
@@ -33,38 +52,99 @@ pub struct ExtendAddressLookupTable<'info> {
 }
 
 pub fn extend_address_lookup_table(ctx: Context<ExtendAddressLookupTable>) -> Result<()> {
-    let signer = &ctx.accounts.signer;
-    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
-    let address_lookup_table = &ctx.accounts.address_lookup_table;
-    let system_program = &ctx.accounts.system_program;
+    for account in ctx.remaining_accounts {
+        require!(
+            ctx.accounts
+                .user_address_lookup_table
+                .allows_owner(account.owner),
+            LutError::OwnerNotAllowed
+        );
+    }
+    let addresses = ctx.remaining_accounts.iter().map(|acc| *acc.key);
+    extend_wrapped_lookup_table(
+        &ctx.accounts.signer,
+        ctx.accounts.payer.as_ref(),
+        &ctx.accounts.config,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.address_lookup_table,
+        &mut ctx.accounts.user_address_lookup_table,
+        addresses,
+        ExtendMode::Strict,
+    )?;
+    Ok(())
+}
+
+/// Shared extend path: checks the signer is the owner or an active delegate, rejects
+/// frozen or deactivated LUTs, enforces the configured cooldown unless the LUT is in
+/// burst-fill mode, deduplicates the candidates against the LUT and within the batch,
+/// checks the 256 limit, collects the per-address fee and CPIs into the native program.
+///
+/// In `ExtendMode::Ensure` the write checks only run once deduplication has found
+/// something new, and a batch with nothing new succeeds without writing.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn extend_wrapped_lookup_table<'info>(
+    signer: &Signer<'info>,
+    payer: Option<&Signer<'info>>,
+    config: &Config,
+    treasury: Option<&UncheckedAccount<'info>>,
+    system_program: &Program<'info, System>,
+    address_lookup_table: &UncheckedAccount<'info>,
+    user_address_lookup_table: &mut Account<'info, UserAddressLookupTable>,
+    addresses: impl IntoIterator<Item = Pubkey>,
+    mode: ExtendMode,
+) -> Result<ExtendResult> {
+    let payer = payer.unwrap_or(signer);
     let clock = Clock::get()?;
-    require!(
-        user_address_lookup_table.is_ready(clock.slot),
-        LutError::LutNotReady
-    );
+    if mode != ExtendMode::Ensure {
+        check_can_write(config, user_address_lookup_table, &signer.key(), clock.slot)?;
+    }
     let lut_data = address_lookup_table.try_borrow_data()?;
     let lut =
         AddressLookupTable::deserialize(&lut_data).map_err(|_| LutError::InvalidLookupTable)?;
     let existing_addresses = lut.addresses;
-    let new_addresses: Vec<Pubkey> = ctx
-        .remaining_accounts
-        .iter()
-        .map(|acc| *acc.key)
-        .filter(|addr| !existing_addresses.contains(addr))
-        .collect();
+    let mut new_addresses: Vec<Pubkey> = Vec::new();
+    let mut duplicates_dropped: u32 = 0;
+    for addr in addresses {
+        if existing_addresses.contains(&addr) {
+            continue;
+        }
+        if new_addresses.contains(&addr) {
+            duplicates_dropped += 1;
+            continue;
+        }
+        new_addresses.push(addr);
+    }
+    if new_addresses.is_empty() && mode == ExtendMode::Ensure {
+        return Ok(ExtendResult {
+            addresses_added: 0,
+            total_addresses: existing_addresses.len() as u32,
+            addresses_rejected: 0,
+        });
+    }
     require!(!new_addresses.is_empty(), LutError::NoNewAddresses);
-    user_address_lookup_table.size += new_addresses.len() as u64;
+    if mode == ExtendMode::Ensure {
+        check_can_write(config, user_address_lookup_table, &signer.key(), clock.slot)?;
+    }
+    let room = UserAddressLookupTable::MAX_ADDRESSES.saturating_sub(existing_addresses.len());
+    let mut addresses_rejected: u32 = 0;
+    if mode == ExtendMode::PartialFill && new_addresses.len() > room {
+        require!(room > 0, LutError::MaxAddressesExceeded);
+        addresses_rejected = (new_addresses.len() - room) as u32;
+        new_addresses.truncate(room);
+    }
     let total_after = existing_addresses.len().saturating_add(new_addresses.len());
     require!(
         total_after <= UserAddressLookupTable::MAX_ADDRESSES,
         LutError::MaxAddressesExceeded
     );
+    user_address_lookup_table.size += new_addresses.len() as u64;
     drop(lut_data);
-    user_address_lookup_table.last_updated_slot = clock.slot;
+    user_address_lookup_table.touch(clock.slot, config.cooldown_slots);
     let ix = extend_lookup_table(
         address_lookup_table.key(),
         user_address_lookup_table.key(),
-        Some(signer.key()),
+        Some(payer.key()),
         new_addresses.clone(),
     );
     let seeds = user_address_lookup_table.seeds();
@@ -73,18 +153,80 @@ pub fn extend_address_lookup_table(ctx: Context<ExtendAddressLookupTable>) -> Re
     program::invoke_signed(
         &ix,
         &[
-            signer.to_account_info(),
+            payer.to_account_info(),
             system_program.to_account_info(),
             address_lookup_table.to_account_info(),
             user_address_lookup_table.to_account_info(),
         ],
         signer_seeds,
     )?;
+    let fee_lamports = config.extend_fee(new_addresses.len());
+    collect_fee(
+        &payer.to_account_info(),
+        treasury,
+        system_program,
+        fee_lamports,
+    )?;
 
     emit!(LutExtended {
         wrapper: user_address_lookup_table.key(),
         addresses_added: new_addresses.len() as u32,
         total_addresses: total_after as u32,
+        duplicates_dropped,
+        fee_lamports,
+        addresses_rejected,
     });
+    Ok(ExtendResult {
+        addresses_added: new_addresses.len() as u32,
+        total_addresses: total_after as u32,
+        addresses_rejected,
+    })
+}
+
+/// Checks that `signer` may write to the LUT now.
+fn check_can_write(
+    config: &Config,
+    user_address_lookup_table: &UserAddressLookupTable,
+    signer: &Pubkey,
+    current_slot: u64,
+) -> Result<()> {
+    require!(!config.paused, LutError::ProgramPaused);
+    require!(
+        user_address_lookup_table.can_extend(signer, current_slot),
+        LutError::UnauthorizedExtender
+    );
+    require!(!user_address_lookup_table.is_frozen(), LutError::LutFrozen);
+    require!(
+        !user_address_lookup_table.is_deactivated(),
+        LutError::LutDeactivated
+    );
+    require!(
+        user_address_lookup_table.can_write(current_slot),
+        LutError::LutNotReady
+    );
     Ok(())
 }
+
+/// Transfers `amount` lamports from the payer to the treasury. A zero amount is a
+/// no-op, so the treasury account only has to be supplied when a fee is configured.
+pub(crate) fn collect_fee<'info>(
+    payer: &AccountInfo<'info>,
+    treasury: Option<&UncheckedAccount<'info>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let treasury = treasury.ok_or(LutError::MissingTreasury)?;
+    transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.clone(),
+                to: treasury.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
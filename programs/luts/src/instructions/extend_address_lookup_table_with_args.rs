@@ -0,0 +1,61 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::instructions::{extend_wrapped_lookup_table, ExtendMode};
+use crate::state::config::Config;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Extends an Address Lookup Table with addresses passed as instruction data.
+///
+/// Behaves like `extend_address_lookup_table`, but the addresses travel in the
+/// instruction data instead of remaining_accounts, so they neither count toward the
+/// transaction's account-lock limit nor get loaded by the runtime. Since the addresses'
+/// owners can't be checked, wrappers with an owner allowlist are refused.
+#[derive(Accounts)]
+pub struct ExtendAddressLookupTableWithArgs<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ LutError::ProgramPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    /// CHECK: Validated via has_one constraint on wrapper
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = address_lookup_table,
+        constraint = !user_address_lookup_table.has_owner_allowlist() @ LutError::OwnerNotAllowed,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn extend_address_lookup_table_with_args(
+    ctx: Context<ExtendAddressLookupTableWithArgs>,
+    addresses: Vec<Pubkey>,
+) -> Result<()> {
+    extend_wrapped_lookup_table(
+        &ctx.accounts.signer,
+        ctx.accounts.payer.as_ref(),
+        &ctx.accounts.config,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.address_lookup_table,
+        &mut ctx.accounts.user_address_lookup_table,
+        addresses,
+        ExtendMode::Strict,
+    )?;
+    Ok(())
+}
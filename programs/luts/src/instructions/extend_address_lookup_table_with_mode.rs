@@ -0,0 +1,102 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::instructions::extend_wrapped_lookup_table;
+use crate::state::config::Config;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::borsh;
+use anchor_lang::prelude::*;
+
+/// How an extend treats a batch with nothing new to add.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExtendMode {
+    /// Fails with `NoNewAddresses` when nothing is new, and checks the signer, status and
+    /// cooldown before looking at the addresses.
+    #[default]
+    Strict,
+    /// Succeeds without writing when every address is already present. The signer,
+    /// status, pause and cooldown checks only apply once something needs adding.
+    Ensure,
+    /// Writes as many new addresses as fit and reports the rest in `addresses_rejected`
+    /// instead of failing with `MaxAddressesExceeded`. Fails if none fit.
+    PartialFill,
+}
+
+/// Outcome of an extend, returned to the caller as return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExtendResult {
+    /// Addresses written by this instruction; 0 if nothing was written.
+    pub addresses_added: u32,
+    /// Addresses in the LUT after the instruction.
+    pub total_addresses: u32,
+    /// New addresses left out because the LUT is full; only non-zero in `PartialFill`.
+    pub addresses_rejected: u32,
+}
+
+/// Arguments for extending with an explicit mode.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExtendAddressLookupTableWithModeArgs {
+    /// Addresses to add to the LUT.
+    pub addresses: Vec<Pubkey>,
+    pub mode: ExtendMode,
+}
+
+/// Extends an Address Lookup Table with addresses passed as instruction data, using the
+/// given `ExtendMode`.
+///
+/// With `ExtendMode::Ensure` this can be placed in any transaction that just needs the
+/// addresses to be present: it is a no-op when they already are, and the returned
+/// `ExtendResult` tells the caller whether anything was written. Pausing is checked
+/// inside the instruction, so an ensure with nothing to add still succeeds while paused.
+///
+/// With `ExtendMode::PartialFill` the first new addresses that fit are written, in input
+/// order after dedupe, and the count left over is returned so the caller can route them
+/// to another LUT.
+///
+/// Like `extend_address_lookup_table_with_args`, this refuses wrappers with an owner
+/// allowlist, since the addresses' owners can't be checked.
+#[derive(Accounts)]
+pub struct ExtendAddressLookupTableWithMode<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    /// CHECK: Validated via has_one constraint on wrapper
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = address_lookup_table,
+        constraint = !user_address_lookup_table.has_owner_allowlist() @ LutError::OwnerNotAllowed,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn extend_address_lookup_table_with_mode(
+    ctx: Context<ExtendAddressLookupTableWithMode>,
+    args: ExtendAddressLookupTableWithModeArgs,
+) -> Result<ExtendResult> {
+    extend_wrapped_lookup_table(
+        &ctx.accounts.signer,
+        ctx.accounts.payer.as_ref(),
+        &ctx.accounts.config,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.address_lookup_table,
+        &mut ctx.accounts.user_address_lookup_table,
+        args.addresses,
+        args.mode,
+    )
+}
@@ -0,0 +1,240 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::events::{LutSetExtended, LutSetMemberAdded};
+use crate::instructions::{
+    create_wrapped_lookup_table, extend_wrapped_lookup_table, CreateAddressLookupTableArgs,
+    ExtendMode,
+};
+use crate::state::config::Config;
+use crate::state::lut_counter::LutCounter;
+use crate::state::lut_set::LutSet;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+
+/// Where `extend_lut_set` creates a new member when the existing ones are full.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LutSetSpill {
+    /// Wrapper id for the new member, as in `create_address_lookup_table`.
+    pub id: u64,
+    /// A recent slot used to derive the new member's LUT address.
+    pub recent_slot: u64,
+}
+
+/// Arguments for extending a LUT set.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExtendLutSetArgs {
+    /// Addresses to add to the set.
+    pub addresses: Vec<Pubkey>,
+    /// Creates a new member for addresses that don't fit, or None to fail instead.
+    pub spill: Option<LutSetSpill>,
+}
+
+/// Extends a LUT set, treating its members as one table.
+///
+/// Every member is passed via remaining_accounts as a `(wrapper, lut)` pair in fill order.
+/// Members that were deactivated or now belong to another authority are no longer usable:
+/// addresses are not deduped against them and they are never written. Addresses already in
+/// a usable member, and repeats within the batch, are dropped. The rest fill members in
+/// order, skipping unusable and frozen ones as well as ones with an owner allowlist, which
+/// can't be checked against addresses passed as data; each member written goes through the
+/// same checks as `extend_address_lookup_table`. Closed members must be removed with
+/// `remove_lut_set_member` first. Addresses left over once the last member is full are
+/// written to a new member created from `spill`, which also requires
+/// `new_user_address_lookup_table`, `new_address_lookup_table` and the signer's
+/// `lut_counter`.
+#[derive(Accounts)]
+pub struct ExtendLutSet<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ LutError::ProgramPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [LutSet::SEED.as_bytes(), lut_set.signer.as_ref(), lut_set.id.to_le_bytes().as_ref()],
+        bump = lut_set.bump
+    )]
+    pub lut_set: Box<Account<'info, LutSet>>,
+    /// CHECK: Validated inside instruction against the wrapper PDA for `spill.id`
+    #[account(mut)]
+    pub new_user_address_lookup_table: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated inside instruction against the address derived from `spill.recent_slot`
+    #[account(mut)]
+    pub new_address_lookup_table: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated inside instruction against the signer's counter PDA
+    #[account(mut)]
+    pub lut_counter: Option<UncheckedAccount<'info>>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn extend_lut_set<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExtendLutSet<'info>>,
+    args: ExtendLutSetArgs,
+) -> Result<()> {
+    let member_keys = ctx.accounts.lut_set.members().to_vec();
+    let owner = ctx.accounts.lut_set.signer;
+    require!(
+        ctx.remaining_accounts.len() == member_keys.len() * 2,
+        LutError::InvalidLutSetMember
+    );
+    let mut members = Vec::with_capacity(member_keys.len());
+    let mut existing_addresses: Vec<Pubkey> = Vec::new();
+    for (pair, member_key) in ctx.remaining_accounts.chunks(2).zip(&member_keys) {
+        let wrapper: Account<'info, UserAddressLookupTable> = Account::try_from(&pair[0])?;
+        require_keys_eq!(wrapper.key(), *member_key, LutError::InvalidLutSetMember);
+        require_keys_eq!(
+            pair[1].key(),
+            wrapper.address_lookup_table,
+            LutError::InvalidLutSetMember
+        );
+        let lut_data = pair[1].try_borrow_data()?;
+        let lut =
+            AddressLookupTable::deserialize(&lut_data).map_err(|_| LutError::InvalidLookupTable)?;
+        let usable = !wrapper.is_deactivated() && wrapper.signer == owner;
+        let room = if !usable || wrapper.is_frozen() || wrapper.has_owner_allowlist() {
+            0
+        } else {
+            UserAddressLookupTable::MAX_ADDRESSES.saturating_sub(lut.addresses.len())
+        };
+        if usable {
+            existing_addresses.extend_from_slice(&lut.addresses);
+        }
+        drop(lut_data);
+        members.push((wrapper, UncheckedAccount::try_from(&pair[1]), room));
+    }
+
+    let mut new_addresses: Vec<Pubkey> = Vec::new();
+    let mut duplicates_dropped: u32 = 0;
+    for addr in args.addresses {
+        if existing_addresses.contains(&addr) {
+            continue;
+        }
+        if new_addresses.contains(&addr) {
+            duplicates_dropped += 1;
+            continue;
+        }
+        new_addresses.push(addr);
+    }
+    require!(!new_addresses.is_empty(), LutError::NoNewAddresses);
+
+    let mut remaining = new_addresses.as_slice();
+    let mut members_written: u8 = 0;
+    for (wrapper, address_lookup_table, room) in members.iter_mut() {
+        if remaining.is_empty() {
+            break;
+        }
+        if *room == 0 {
+            continue;
+        }
+        let (chunk, rest) = remaining.split_at((*room).min(remaining.len()));
+        extend_wrapped_lookup_table(
+            &ctx.accounts.signer,
+            ctx.accounts.payer.as_ref(),
+            &ctx.accounts.config,
+            ctx.accounts.treasury.as_ref(),
+            &ctx.accounts.system_program,
+            address_lookup_table,
+            wrapper,
+            chunk.iter().copied(),
+            ExtendMode::Strict,
+        )?;
+        wrapper.exit(&crate::ID)?;
+        members_written += 1;
+        remaining = rest;
+    }
+
+    if !remaining.is_empty() {
+        let spill = args.spill.ok_or(LutError::MaxAddressesExceeded)?;
+        require!(!ctx.accounts.lut_set.is_full(), LutError::LutSetFull);
+        let (Some(new_user_address_lookup_table), Some(new_address_lookup_table)) = (
+            &ctx.accounts.new_user_address_lookup_table,
+            &ctx.accounts.new_address_lookup_table,
+        ) else {
+            return err!(LutError::InvalidLutSetMember);
+        };
+        let lut_counter = ctx
+            .accounts
+            .lut_counter
+            .as_ref()
+            .ok_or(LutError::MissingLutCounter)?;
+        let signer = &ctx.accounts.signer;
+        let (expected, bump) = Pubkey::find_program_address(
+            &[
+                UserAddressLookupTable::SEED.as_bytes(),
+                signer.key().as_ref(),
+                spill.id.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            new_user_address_lookup_table.key(),
+            expected,
+            LutError::InvalidLutSetMember
+        );
+        let (expected_counter, counter_bump) = Pubkey::find_program_address(
+            &[LutCounter::SEED.as_bytes(), signer.key().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            lut_counter.key(),
+            expected_counter,
+            LutError::MissingLutCounter
+        );
+        let payer = match &ctx.accounts.payer {
+            Some(payer) => payer.to_account_info(),
+            None => signer.to_account_info(),
+        };
+        create_wrapped_lookup_table(
+            signer,
+            &payer,
+            &ctx.accounts.config,
+            ctx.accounts.treasury.as_ref(),
+            &ctx.accounts.system_program,
+            &ctx.accounts.address_lookup_table_program,
+            new_address_lookup_table,
+            new_user_address_lookup_table,
+            &ctx.accounts.rent,
+            bump,
+            None,
+            lut_counter,
+            counter_bump,
+            [0; 32],
+            CreateAddressLookupTableArgs {
+                recent_slot: spill.recent_slot,
+                id: spill.id,
+                initial_addresses: Some(remaining.to_vec()),
+                burst_fill: None,
+                label: None,
+                metadata_uri: None,
+            },
+        )?;
+        let lut_set = &mut ctx.accounts.lut_set;
+        let index = lut_set.push(new_user_address_lookup_table.key());
+        emit!(LutSetMemberAdded {
+            lut_set: lut_set.key(),
+            wrapper: new_user_address_lookup_table.key(),
+            index,
+        });
+        members_written += 1;
+    }
+
+    emit!(LutSetExtended {
+        lut_set: ctx.accounts.lut_set.key(),
+        addresses_added: new_addresses.len() as u32,
+        duplicates_dropped,
+        members_written,
+    });
+    Ok(())
+}
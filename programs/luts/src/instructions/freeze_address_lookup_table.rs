@@ -0,0 +1,57 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::events::LutFrozen;
+use crate::state::user_address_lookup_table::{LutStatus, UserAddressLookupTable};
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::instruction::freeze_lookup_table;
+
+/// Freezes an Address Lookup Table, making it permanently immutable.
+///
+/// The native program refuses to freeze an empty or deactivated LUT. Once frozen, the LUT
+/// can never be extended, deactivated or closed, and its rent stays locked.
+#[derive(Accounts)]
+pub struct FreezeAddressLookupTable<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    /// CHECK: Validated via has_one constraint on wrapper
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = address_lookup_table,
+        has_one = signer,
+        constraint = !user_address_lookup_table.is_frozen() @ LutError::LutFrozen,
+        constraint = !user_address_lookup_table.is_deactivated() @ LutError::LutDeactivated,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn freeze_address_lookup_table(ctx: Context<FreezeAddressLookupTable>) -> Result<()> {
+    let signer = &ctx.accounts.signer;
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let address_lookup_table = &ctx.accounts.address_lookup_table;
+    let seeds = user_address_lookup_table.seeds();
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|v| v.as_slice()).collect();
+    let signer_seeds: &[&[&[u8]]] = &[seed_slices.as_slice()];
+    let ix = freeze_lookup_table(address_lookup_table.key(), user_address_lookup_table.key());
+    program::invoke_signed(
+        &ix,
+        &[
+            signer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            address_lookup_table.to_account_info(),
+            user_address_lookup_table.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+    user_address_lookup_table.status = LutStatus::Frozen;
+    emit!(LutFrozen {
+        wrapper: user_address_lookup_table.key(),
+        lut_address: address_lookup_table.key(),
+    });
+    Ok(())
+}
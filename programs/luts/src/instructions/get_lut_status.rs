@@ -0,0 +1,64 @@
+use crate::error::LutError;
+use crate::state::user_address_lookup_table::{LutStatus, UserAddressLookupTable};
+use anchor_lang::prelude::borsh;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+
+/// Snapshot of a wrapped LUT, returned to the caller as return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct LutStatusInfo {
+    /// True if the cooldown has passed and the LUT is not deactivated, i.e. it can be
+    /// used in a transaction at the current slot.
+    pub ready: bool,
+    /// Slot from which the LUT is ready.
+    pub ready_at_slot: u64,
+    /// Addresses in the native LUT.
+    pub address_count: u32,
+    /// Lifecycle state of the native LUT.
+    pub status: LutStatus,
+    /// Slot at which the LUT was deactivated, or 0 if it hasn't been.
+    pub deactivated_slot: u64,
+    /// The native LUT address.
+    pub address_lookup_table: Pubkey,
+}
+
+/// Reports whether a wrapped Address Lookup Table is usable, without modifying anything.
+///
+/// Intended for programs that consume the LUTs via CPI, so they don't have to mirror
+/// `UserAddressLookupTable::is_ready` or parse the native account themselves. `status` and
+/// `deactivated_slot` are read from the native table rather than the wrapper, so wrappers
+/// that never recorded them, such as legacy ones, still report a deactivated or frozen LUT.
+#[derive(Accounts)]
+pub struct GetLutStatus<'info> {
+    /// CHECK: Validated via has_one constraint on wrapper
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        has_one = address_lookup_table,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn get_lut_status(ctx: Context<GetLutStatus>) -> Result<LutStatusInfo> {
+    let wrapper = &ctx.accounts.user_address_lookup_table;
+    let lut_data = ctx.accounts.address_lookup_table.try_borrow_data()?;
+    let lut =
+        AddressLookupTable::deserialize(&lut_data).map_err(|_| LutError::InvalidLookupTable)?;
+    let (status, deactivated_slot) = if lut.meta.deactivation_slot != u64::MAX {
+        (LutStatus::Deactivated, lut.meta.deactivation_slot)
+    } else if lut.meta.authority.is_none() {
+        (LutStatus::Frozen, 0)
+    } else {
+        (LutStatus::Active, 0)
+    };
+    let clock = Clock::get()?;
+    Ok(LutStatusInfo {
+        ready: status != LutStatus::Deactivated && wrapper.is_ready(clock.slot),
+        ready_at_slot: wrapper.ready_at_slot,
+        address_count: lut.addresses.len() as u32,
+        status,
+        deactivated_slot,
+        address_lookup_table: wrapper.address_lookup_table,
+    })
+}
@@ -0,0 +1,63 @@
+use crate::error::LutError;
+use crate::events::LutDelegateGranted;
+use crate::state::user_address_lookup_table::{LutDelegate, UserAddressLookupTable};
+use anchor_lang::prelude::*;
+
+/// Arguments for granting a delegated extender.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GrantDelegateArgs {
+    /// The key allowed to extend the LUT.
+    pub delegate: Pubkey,
+    /// Slot at which the grant expires, or None for a grant that never expires.
+    pub expires_at_slot: Option<u64>,
+}
+
+/// Grants a key permission to extend the wrapped LUT on the owner's behalf.
+///
+/// Delegates can only extend; deactivation, closing and authority changes stay with the
+/// owner. Granting an existing delegate again replaces its expiry. Expired grants are
+/// reused when the list is full.
+#[derive(Accounts)]
+pub struct GrantDelegate<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn grant_delegate(ctx: Context<GrantDelegate>, args: GrantDelegateArgs) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let clock = Clock::get()?;
+    let expires_at_slot = match args.expires_at_slot {
+        Some(slot) => {
+            require!(slot > clock.slot, LutError::InvalidDelegateExpiry);
+            slot
+        }
+        None => 0,
+    };
+    let index = user_address_lookup_table
+        .delegates
+        .iter()
+        .position(|delegate| delegate.key == args.delegate)
+        .or_else(|| {
+            user_address_lookup_table
+                .delegates
+                .iter()
+                .position(|delegate| !delegate.is_active(clock.slot))
+        })
+        .ok_or(LutError::MaxDelegatesExceeded)?;
+    user_address_lookup_table.delegates[index] = LutDelegate {
+        key: args.delegate,
+        expires_at_slot,
+    };
+    emit!(LutDelegateGranted {
+        wrapper: user_address_lookup_table.key(),
+        delegate: args.delegate,
+        expires_at_slot,
+    });
+    Ok(())
+}
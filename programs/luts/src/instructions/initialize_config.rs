@@ -0,0 +1,73 @@
+use crate::error::LutError;
+use crate::events::ConfigUpdated;
+use crate::program::Luts;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Arguments for initializing the program config.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeConfigArgs {
+    /// The key allowed to update the config.
+    pub admin: Pubkey,
+    /// A key that can pause the program, or `Pubkey::default()` for none.
+    pub guardian: Pubkey,
+    /// Slots to wait after a write before a LUT is ready.
+    pub cooldown_slots: u64,
+    /// Maximum LUTs per signer; 0 means unlimited.
+    pub max_luts_per_signer: u64,
+    /// Receives protocol fees, or `Pubkey::default()` when no fee is charged.
+    pub treasury: Pubkey,
+    /// Flat fee in lamports charged on create.
+    pub create_fee_lamports: u64,
+    /// Fee in lamports charged per address written.
+    pub extend_fee_lamports_per_address: u64,
+}
+
+/// Creates the singleton config PDA.
+///
+/// Only the program's upgrade authority can initialize the config, which prevents
+/// anyone else from claiming the admin role after deployment.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Luts>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(signer.key()))]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(
+        init,
+        payer = signer,
+        space = Config::SIZE,
+        seeds = [Config::SEED.as_bytes()],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+pub fn initialize_config(ctx: Context<InitializeConfig>, args: InitializeConfigArgs) -> Result<()> {
+    require!(args.cooldown_slots > 0, LutError::InvalidConfig);
+    let config = &mut ctx.accounts.config;
+    config.bump = ctx.bumps.config;
+    config.admin = args.admin;
+    config.guardian = args.guardian;
+    config.cooldown_slots = args.cooldown_slots;
+    config.max_luts_per_signer = args.max_luts_per_signer;
+    config.paused = false;
+    config.treasury = args.treasury;
+    config.create_fee_lamports = args.create_fee_lamports;
+    config.extend_fee_lamports_per_address = args.extend_fee_lamports_per_address;
+    require!(config.has_valid_fees(), LutError::InvalidConfig);
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        guardian: config.guardian,
+        cooldown_slots: config.cooldown_slots,
+        max_luts_per_signer: config.max_luts_per_signer,
+        paused: config.paused,
+        treasury: config.treasury,
+        create_fee_lamports: config.create_fee_lamports,
+        extend_fee_lamports_per_address: config.extend_fee_lamports_per_address,
+    });
+    Ok(())
+}
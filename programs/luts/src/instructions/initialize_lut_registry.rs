@@ -0,0 +1,38 @@
+use crate::events::LutRegistryInitialized;
+use crate::state::lut_registry::LutRegistry;
+use anchor_lang::prelude::*;
+
+/// Creates the signer's LUT registry.
+///
+/// `next_id` is the lowest id a registered create may use; the caller still picks the id,
+/// usually by reading `next_id`. Signers that already created wrappers by hand should start
+/// past their highest id so registered creates don't collide with them; an id taken later
+/// without the registry is skipped by creating with a higher one.
+#[derive(Accounts)]
+pub struct InitializeLutRegistry<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        init,
+        payer = signer,
+        space = LutRegistry::space(0),
+        seeds = [LutRegistry::SEED.as_bytes(), signer.key().as_ref()],
+        bump
+    )]
+    pub lut_registry: Box<Account<'info, LutRegistry>>,
+}
+
+pub fn initialize_lut_registry(ctx: Context<InitializeLutRegistry>, next_id: u64) -> Result<()> {
+    let lut_registry = &mut ctx.accounts.lut_registry;
+    lut_registry.bump = ctx.bumps.lut_registry;
+    lut_registry.signer = ctx.accounts.signer.key();
+    lut_registry.next_id = next_id;
+    lut_registry.live_ids = Vec::new();
+    emit!(LutRegistryInitialized {
+        lut_registry: lut_registry.key(),
+        authority: lut_registry.signer,
+        next_id,
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::error::LutError;
+use crate::events::LutMarkedInUse;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Ends burst-fill mode for a wrapped Address Lookup Table.
+///
+/// From here on extends are throttled by the cooldown again. `ready_at_slot` is left
+/// as set by the last write, so clients can tell when the filled LUT becomes usable.
+#[derive(Accounts)]
+pub struct MarkInUse<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        constraint = user_address_lookup_table.burst_fill @ LutError::NotInBurstFill,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn mark_in_use(ctx: Context<MarkInUse>) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    user_address_lookup_table.burst_fill = false;
+    emit!(LutMarkedInUse {
+        wrapper: user_address_lookup_table.key(),
+        ready_at_slot: user_address_lookup_table.ready_at_slot,
+    });
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::error::LutError;
+use crate::events::WrapperMigrated;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Upgrades a legacy wrapper to the current account layout.
+///
+/// Reallocs the account to `UserAddressLookupTable::SIZE`, with the owner paying the rent
+/// difference, and writes the fields the legacy layout lacked with their defaults. The
+/// legacy program could deactivate LUTs without recording it, so callers may want to
+/// follow up with `sync_address_lookup_table`.
+#[derive(Accounts)]
+pub struct MigrateWrapper<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        has_one = signer,
+        constraint = !user_address_lookup_table.is_current() @ LutError::WrapperAlreadyMigrated,
+        realloc = UserAddressLookupTable::SIZE,
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn migrate_wrapper(ctx: Context<MigrateWrapper>) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    user_address_lookup_table.version = UserAddressLookupTable::CURRENT_VERSION;
+    emit!(WrapperMigrated {
+        wrapper: user_address_lookup_table.key(),
+        version: user_address_lookup_table.version,
+    });
+    Ok(())
+}
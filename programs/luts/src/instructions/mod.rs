@@ -1,9 +1,61 @@
+mod accept_authority;
+mod add_lut_set_member;
 mod close_address_lookup_table;
+mod close_orphaned_wrapper;
+mod close_previous_address_lookup_table;
 mod create_address_lookup_table;
+mod create_address_lookup_table_with_namespace;
+mod create_lut_set;
 mod deactivate_address_lookup_table;
 mod extend_address_lookup_table;
+mod extend_address_lookup_table_with_args;
+mod extend_address_lookup_table_with_mode;
+mod extend_lut_set;
+mod freeze_address_lookup_table;
+mod get_lut_status;
+mod grant_delegate;
+mod initialize_config;
+mod initialize_lut_registry;
+mod mark_in_use;
+mod migrate_wrapper;
+mod propose_authority;
+mod remove_lut_set_member;
+mod resolve_address_indexes;
+mod revoke_delegate;
+mod rotate_address_lookup_table;
+mod set_owner_allowlist;
+mod set_paused;
+mod set_wrapper_metadata;
+mod sync_address_lookup_table;
+mod update_config;
 
+pub use accept_authority::*;
+pub use add_lut_set_member::*;
 pub use close_address_lookup_table::*;
+pub use close_orphaned_wrapper::*;
+pub use close_previous_address_lookup_table::*;
 pub use create_address_lookup_table::*;
+pub use create_address_lookup_table_with_namespace::*;
+pub use create_lut_set::*;
 pub use deactivate_address_lookup_table::*;
 pub use extend_address_lookup_table::*;
+pub use extend_address_lookup_table_with_args::*;
+pub use extend_address_lookup_table_with_mode::*;
+pub use extend_lut_set::*;
+pub use freeze_address_lookup_table::*;
+pub use get_lut_status::*;
+pub use grant_delegate::*;
+pub use initialize_config::*;
+pub use initialize_lut_registry::*;
+pub use mark_in_use::*;
+pub use migrate_wrapper::*;
+pub use propose_authority::*;
+pub use remove_lut_set_member::*;
+pub use resolve_address_indexes::*;
+pub use revoke_delegate::*;
+pub use rotate_address_lookup_table::*;
+pub use set_owner_allowlist::*;
+pub use set_paused::*;
+pub use set_wrapper_metadata::*;
+pub use sync_address_lookup_table::*;
+pub use update_config::*;
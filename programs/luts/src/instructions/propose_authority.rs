@@ -0,0 +1,31 @@
+use crate::events::LutAuthorityProposed;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Proposes a new authority for a wrapped Address Lookup Table.
+///
+/// This is the first step of a two-step transfer; the proposed key must call
+/// `accept_authority` before it gains control. Proposing `Pubkey::default()`
+/// cancels a pending transfer.
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    user_address_lookup_table.pending_authority = new_authority;
+    emit!(LutAuthorityProposed {
+        wrapper: user_address_lookup_table.key(),
+        authority: ctx.accounts.signer.key(),
+        pending_authority: new_authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::error::LutError;
+use crate::events::LutSetMemberRemoved;
+use crate::state::lut_set::LutSet;
+use anchor_lang::prelude::*;
+
+/// Removes a wrapper from a LUT set, keeping the remaining members in fill order.
+///
+/// `member` is taken by key so that wrappers which were closed, or whose authority moved
+/// to another key, can still be removed. `extend_lut_set` requires every member to be
+/// passed, so such wrappers must be removed before the set can be extended again.
+#[derive(Accounts)]
+pub struct RemoveLutSetMember<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [LutSet::SEED.as_bytes(), lut_set.signer.as_ref(), lut_set.id.to_le_bytes().as_ref()],
+        bump = lut_set.bump
+    )]
+    pub lut_set: Box<Account<'info, LutSet>>,
+}
+
+pub fn remove_lut_set_member(ctx: Context<RemoveLutSetMember>, member: Pubkey) -> Result<()> {
+    let lut_set = &mut ctx.accounts.lut_set;
+    let index = lut_set
+        .remove(&member)
+        .ok_or(LutError::InvalidLutSetMember)?;
+    emit!(LutSetMemberRemoved {
+        lut_set: lut_set.key(),
+        wrapper: member,
+        index,
+    });
+    Ok(())
+}
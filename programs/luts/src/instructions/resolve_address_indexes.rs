@@ -0,0 +1,46 @@
+use crate::error::LutError;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+
+/// Index returned by `resolve_address_indexes` for an address that is not in the LUT.
+pub const ADDRESS_NOT_FOUND: u16 = u16::MAX;
+
+/// Looks up the index of each given address in a wrapped Address Lookup Table.
+///
+/// Returns one `u16` per input address, in input order, as return data: the address's
+/// position in the native LUT, or `ADDRESS_NOT_FOUND`. At most 256 addresses can be
+/// resolved per call. Indexes are only usable in a v0 message once the LUT is ready.
+#[derive(Accounts)]
+pub struct ResolveAddressIndexes<'info> {
+    /// CHECK: Validated via has_one constraint on wrapper
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        has_one = address_lookup_table,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn resolve_address_indexes(
+    ctx: Context<ResolveAddressIndexes>,
+    addresses: Vec<Pubkey>,
+) -> Result<Vec<u16>> {
+    require!(
+        addresses.len() <= UserAddressLookupTable::MAX_ADDRESSES,
+        LutError::MaxAddressesExceeded
+    );
+    let lut_data = ctx.accounts.address_lookup_table.try_borrow_data()?;
+    let lut =
+        AddressLookupTable::deserialize(&lut_data).map_err(|_| LutError::InvalidLookupTable)?;
+    Ok(addresses
+        .iter()
+        .map(|addr| {
+            lut.addresses
+                .iter()
+                .position(|existing| existing == addr)
+                .map_or(ADDRESS_NOT_FOUND, |index| index as u16)
+        })
+        .collect())
+}
@@ -0,0 +1,32 @@
+use crate::error::LutError;
+use crate::events::LutDelegateRevoked;
+use crate::state::user_address_lookup_table::{LutDelegate, UserAddressLookupTable};
+use anchor_lang::prelude::*;
+
+/// Revokes a delegated extender from the wrapped LUT.
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn revoke_delegate(ctx: Context<RevokeDelegate>, delegate: Pubkey) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let index = user_address_lookup_table
+        .delegates
+        .iter()
+        .position(|entry| !entry.is_empty() && entry.key == delegate)
+        .ok_or(LutError::DelegateNotFound)?;
+    user_address_lookup_table.delegates[index] = LutDelegate::default();
+    emit!(LutDelegateRevoked {
+        wrapper: user_address_lookup_table.key(),
+        delegate,
+    });
+    Ok(())
+}
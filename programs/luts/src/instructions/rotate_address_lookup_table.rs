@@ -0,0 +1,122 @@
+use crate::constants::LutProgram;
+use crate::error::LutError;
+use crate::events::LutRotated;
+use crate::instructions::collect_fee;
+use crate::state::config::Config;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, deactivate_lookup_table,
+};
+
+/// Replaces the wrapper's native LUT with a fresh, empty one.
+///
+/// The current LUT is deactivated and kept as `previous_address_lookup_table` until it is
+/// closed with `close_previous_address_lookup_table`, and `generation` is bumped. The
+/// wrapper PDA stays the same, so clients keep following one address. Rent for the new
+/// LUT and the configured create fee are paid by the optional `payer`, falling back to
+/// the signer.
+#[derive(Accounts)]
+pub struct RotateAddressLookupTable<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ LutError::ProgramPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Must match the config treasury; only required when a fee is charged
+    #[account(mut, address = config.treasury @ LutError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+    pub address_lookup_table_program: Program<'info, LutProgram>,
+    /// CHECK: Validated via has_one constraint on wrapper
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+    /// CHECK: Validated inside instruction against the address derived from `recent_slot`
+    #[account(mut)]
+    pub new_address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = address_lookup_table,
+        has_one = signer,
+        constraint = !user_address_lookup_table.is_frozen() @ LutError::LutFrozen,
+        constraint = !user_address_lookup_table.is_deactivated() @ LutError::LutDeactivated,
+        constraint = !user_address_lookup_table.has_previous_lookup_table() @ LutError::PreviousLutPending,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn rotate_address_lookup_table(
+    ctx: Context<RotateAddressLookupTable>,
+    recent_slot: u64,
+) -> Result<()> {
+    let payer = match &ctx.accounts.payer {
+        Some(payer) => payer.to_account_info(),
+        None => ctx.accounts.signer.to_account_info(),
+    };
+    let config = &ctx.accounts.config;
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let address_lookup_table = &ctx.accounts.address_lookup_table;
+    let new_address_lookup_table = &ctx.accounts.new_address_lookup_table;
+    let seeds = user_address_lookup_table.seeds();
+    let seed_slices: Vec<&[u8]> = seeds.iter().map(|v| v.as_slice()).collect();
+    let signer_seeds: &[&[&[u8]]] = &[seed_slices.as_slice()];
+
+    let ix = deactivate_lookup_table(address_lookup_table.key(), user_address_lookup_table.key());
+    program::invoke_signed(
+        &ix,
+        &[
+            address_lookup_table.to_account_info(),
+            user_address_lookup_table.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    let (ix, address) =
+        create_lookup_table(user_address_lookup_table.key(), payer.key(), recent_slot);
+    require_keys_eq!(
+        address,
+        new_address_lookup_table.key(),
+        LutError::InvalidLookupTable
+    );
+    program::invoke(
+        &ix,
+        &[
+            payer.clone(),
+            new_address_lookup_table.to_account_info(),
+            user_address_lookup_table.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.address_lookup_table_program.to_account_info(),
+        ],
+    )?;
+
+    let clock = Clock::get()?;
+    user_address_lookup_table.previous_address_lookup_table = address_lookup_table.key();
+    user_address_lookup_table.address_lookup_table = new_address_lookup_table.key();
+    user_address_lookup_table.size = 0;
+    user_address_lookup_table.generation += 1;
+    user_address_lookup_table.touch(clock.slot, config.cooldown_slots);
+
+    let fee_lamports = config.create_fee_lamports;
+    collect_fee(
+        &payer,
+        ctx.accounts.treasury.as_ref(),
+        &ctx.accounts.system_program,
+        fee_lamports,
+    )?;
+    emit!(LutRotated {
+        wrapper: user_address_lookup_table.key(),
+        previous_lut_address: address_lookup_table.key(),
+        lut_address: new_address_lookup_table.key(),
+        generation: user_address_lookup_table.generation,
+        fee_lamports,
+    });
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::error::LutError;
+use crate::events::OwnerAllowlistSet;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Replaces the programs allowed to own addresses added to the wrapped LUT.
+///
+/// While the list is non-empty, `extend_address_lookup_table` rejects any remaining
+/// account whose owner is not in it. Extends that pass addresses as instruction data
+/// can't be checked and are refused until the list is cleared. Passing an empty list
+/// removes the policy; addresses already in the LUT are not re-checked.
+#[derive(Accounts)]
+pub struct SetOwnerAllowlist<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn set_owner_allowlist(
+    ctx: Context<SetOwnerAllowlist>,
+    allowed_owners: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        allowed_owners.len() <= UserAddressLookupTable::MAX_ALLOWED_OWNERS,
+        LutError::MaxAllowedOwnersExceeded
+    );
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    user_address_lookup_table.allowed_owners =
+        [Pubkey::default(); UserAddressLookupTable::MAX_ALLOWED_OWNERS];
+    user_address_lookup_table.allowed_owners[..allowed_owners.len()]
+        .copy_from_slice(&allowed_owners);
+    user_address_lookup_table.allowed_owner_count = allowed_owners.len() as u8;
+    emit!(OwnerAllowlistSet {
+        wrapper: user_address_lookup_table.key(),
+        allowed_owners,
+    });
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::error::LutError;
+use crate::events::{LutsPaused, LutsUnpaused};
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Pauses or unpauses the program.
+///
+/// While paused, create and extend are rejected with `ProgramPaused`; deactivate and
+/// close keep working so users can always reclaim rent. The admin can pause and
+/// unpause, the guardian can only pause.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.can_set_paused(&authority, paused),
+        LutError::NotPauseAuthority
+    );
+    config.paused = paused;
+    if paused {
+        emit!(LutsPaused { authority });
+    } else {
+        emit!(LutsUnpaused { authority });
+    }
+    Ok(())
+}
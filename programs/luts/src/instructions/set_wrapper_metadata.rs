@@ -0,0 +1,42 @@
+use crate::events::LutMetadataSet;
+use crate::state::user_address_lookup_table::UserAddressLookupTable;
+use anchor_lang::prelude::*;
+
+/// Arguments for updating a wrapper's label and metadata URI.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetWrapperMetadataArgs {
+    /// New label, or None to leave it unchanged. An empty string clears it.
+    pub label: Option<String>,
+    /// New metadata URI, or None to leave it unchanged. An empty string clears it.
+    pub metadata_uri: Option<String>,
+}
+
+/// Sets the human-readable label and metadata URI of a wrapper.
+///
+/// Neither value is interpreted on-chain; both are emitted in `LutMetadataSet` for
+/// off-chain indexers.
+#[derive(Accounts)]
+pub struct SetWrapperMetadata<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = signer,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn set_wrapper_metadata(
+    ctx: Context<SetWrapperMetadata>,
+    args: SetWrapperMetadataArgs,
+) -> Result<()> {
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    user_address_lookup_table.set_metadata(args.label, args.metadata_uri)?;
+    emit!(LutMetadataSet {
+        wrapper: user_address_lookup_table.key(),
+        label: user_address_lookup_table.label.clone(),
+        metadata_uri: user_address_lookup_table.metadata_uri.clone(),
+    });
+    Ok(())
+}
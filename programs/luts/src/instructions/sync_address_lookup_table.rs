@@ -0,0 +1,74 @@
+use crate::error::LutError;
+use crate::events::LutSynced;
+use crate::state::config::Config;
+use crate::state::user_address_lookup_table::{LutStatus, UserAddressLookupTable};
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+
+/// Rewrites a wrapper's mirrored fields from its native Address Lookup Table.
+///
+/// Permissionless. `size` is set to the native address count, and `status` and
+/// `deactivated_slot` follow the native table if it has been deactivated or frozen
+/// (lost its authority). If the native table was extended after the wrapper's
+/// `last_updated_slot`, that slot moves up to `last_extended_slot` and `ready_at_slot` is
+/// pushed out by the configured cooldown from there. They never move back, since creates
+/// and rotations touch the wrapper without extending the native table. Emits `LutSynced`
+/// with the values before and after.
+#[derive(Accounts)]
+pub struct SyncAddressLookupTable<'info> {
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Validated via has_one constraint on wrapper
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = address_lookup_table,
+        seeds = [UserAddressLookupTable::SEED.as_bytes(), user_address_lookup_table.creator.as_ref(), user_address_lookup_table.id_seed().as_ref()],
+        bump = user_address_lookup_table.bump
+    )]
+    pub user_address_lookup_table: Box<Account<'info, UserAddressLookupTable>>,
+}
+
+pub fn sync_address_lookup_table(ctx: Context<SyncAddressLookupTable>) -> Result<()> {
+    let lut_data = ctx.accounts.address_lookup_table.try_borrow_data()?;
+    let lut =
+        AddressLookupTable::deserialize(&lut_data).map_err(|_| LutError::InvalidLookupTable)?;
+    let user_address_lookup_table = &mut ctx.accounts.user_address_lookup_table;
+    let size_before = user_address_lookup_table.size;
+    let status_before = user_address_lookup_table.status;
+    let deactivated_slot_before = user_address_lookup_table.deactivated_slot;
+    let last_updated_slot_before = user_address_lookup_table.last_updated_slot;
+    let ready_at_slot_before = user_address_lookup_table.ready_at_slot;
+
+    user_address_lookup_table.size = lut.addresses.len() as u64;
+    if lut.meta.last_extended_slot > user_address_lookup_table.last_updated_slot {
+        user_address_lookup_table.touch(
+            lut.meta.last_extended_slot,
+            ctx.accounts.config.cooldown_slots,
+        );
+    }
+    if lut.meta.deactivation_slot != u64::MAX {
+        user_address_lookup_table.status = LutStatus::Deactivated;
+        user_address_lookup_table.deactivated_slot = lut.meta.deactivation_slot;
+    } else if lut.meta.authority.is_none() {
+        user_address_lookup_table.status = LutStatus::Frozen;
+    }
+
+    emit!(LutSynced {
+        wrapper: user_address_lookup_table.key(),
+        size_before,
+        size_after: user_address_lookup_table.size,
+        status_before,
+        status_after: user_address_lookup_table.status,
+        deactivated_slot_before,
+        deactivated_slot_after: user_address_lookup_table.deactivated_slot,
+        last_updated_slot_before,
+        last_updated_slot_after: user_address_lookup_table.last_updated_slot,
+        ready_at_slot_before,
+        ready_at_slot_after: user_address_lookup_table.ready_at_slot,
+    });
+    Ok(())
+}
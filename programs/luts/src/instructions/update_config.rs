@@ -0,0 +1,70 @@
+use crate::error::LutError;
+use crate::events::ConfigUpdated;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Arguments for updating the program config. Fields left as None are unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateConfigArgs {
+    /// Hands the admin role to another key.
+    pub new_admin: Option<Pubkey>,
+    pub guardian: Option<Pubkey>,
+    pub cooldown_slots: Option<u64>,
+    pub max_luts_per_signer: Option<u64>,
+    pub treasury: Option<Pubkey>,
+    pub create_fee_lamports: Option<u64>,
+    pub extend_fee_lamports_per_address: Option<u64>,
+}
+
+/// Updates the program config. Only callable by the config admin.
+///
+/// Pausing goes through `set_paused` so that it always emits a pause event.
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    if let Some(new_admin) = args.new_admin {
+        config.admin = new_admin;
+    }
+    if let Some(guardian) = args.guardian {
+        config.guardian = guardian;
+    }
+    if let Some(cooldown_slots) = args.cooldown_slots {
+        require!(cooldown_slots > 0, LutError::InvalidConfig);
+        config.cooldown_slots = cooldown_slots;
+    }
+    if let Some(max_luts_per_signer) = args.max_luts_per_signer {
+        config.max_luts_per_signer = max_luts_per_signer;
+    }
+    if let Some(treasury) = args.treasury {
+        config.treasury = treasury;
+    }
+    if let Some(create_fee_lamports) = args.create_fee_lamports {
+        config.create_fee_lamports = create_fee_lamports;
+    }
+    if let Some(extend_fee_lamports_per_address) = args.extend_fee_lamports_per_address {
+        config.extend_fee_lamports_per_address = extend_fee_lamports_per_address;
+    }
+    require!(config.has_valid_fees(), LutError::InvalidConfig);
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        guardian: config.guardian,
+        cooldown_slots: config.cooldown_slots,
+        max_luts_per_signer: config.max_luts_per_signer,
+        paused: config.paused,
+        treasury: config.treasury,
+        create_fee_lamports: config.create_fee_lamports,
+        extend_fee_lamports_per_address: config.extend_fee_lamports_per_address,
+    });
+    Ok(())
+}
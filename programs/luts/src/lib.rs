@@ -2,6 +2,8 @@
 use anchor_lang::prelude::*;
 
 pub mod constants;
+#[cfg(feature = "cpi")]
+pub mod cpi_helpers;
 pub mod error;
 pub mod events;
 pub mod instructions;
@@ -22,10 +24,31 @@ pub mod luts {
         instructions::create_address_lookup_table(ctx, args)
     }
 
+    pub fn create_address_lookup_table_with_namespace(
+        ctx: Context<CreateAddressLookupTableWithNamespace>,
+        args: CreateAddressLookupTableWithNamespaceArgs,
+    ) -> Result<()> {
+        instructions::create_address_lookup_table_with_namespace(ctx, args)
+    }
+
     pub fn extend_address_lookup_table(ctx: Context<ExtendAddressLookupTable>) -> Result<()> {
         instructions::extend_address_lookup_table(ctx)
     }
 
+    pub fn extend_address_lookup_table_with_args(
+        ctx: Context<ExtendAddressLookupTableWithArgs>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::extend_address_lookup_table_with_args(ctx, addresses)
+    }
+
+    pub fn extend_address_lookup_table_with_mode(
+        ctx: Context<ExtendAddressLookupTableWithMode>,
+        args: ExtendAddressLookupTableWithModeArgs,
+    ) -> Result<ExtendResult> {
+        instructions::extend_address_lookup_table_with_mode(ctx, args)
+    }
+
     pub fn deactivate_address_lookup_table(
         ctx: Context<DeactivateAddressLookupTable>,
     ) -> Result<()> {
@@ -35,4 +58,119 @@ pub mod luts {
     pub fn close_address_lookup_table(ctx: Context<CloseAddressLookupTable>) -> Result<()> {
         instructions::close_address_lookup_table(ctx)
     }
+
+    pub fn close_orphaned_wrapper(ctx: Context<CloseOrphanedWrapper>) -> Result<()> {
+        instructions::close_orphaned_wrapper(ctx)
+    }
+
+    pub fn rotate_address_lookup_table(
+        ctx: Context<RotateAddressLookupTable>,
+        recent_slot: u64,
+    ) -> Result<()> {
+        instructions::rotate_address_lookup_table(ctx, recent_slot)
+    }
+
+    pub fn close_previous_address_lookup_table(
+        ctx: Context<ClosePreviousAddressLookupTable>,
+    ) -> Result<()> {
+        instructions::close_previous_address_lookup_table(ctx)
+    }
+
+    pub fn freeze_address_lookup_table(ctx: Context<FreezeAddressLookupTable>) -> Result<()> {
+        instructions::freeze_address_lookup_table(ctx)
+    }
+
+    pub fn mark_in_use(ctx: Context<MarkInUse>) -> Result<()> {
+        instructions::mark_in_use(ctx)
+    }
+
+    pub fn get_lut_status(ctx: Context<GetLutStatus>) -> Result<LutStatusInfo> {
+        instructions::get_lut_status(ctx)
+    }
+
+    pub fn resolve_address_indexes(
+        ctx: Context<ResolveAddressIndexes>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<Vec<u16>> {
+        instructions::resolve_address_indexes(ctx, addresses)
+    }
+
+    pub fn sync_address_lookup_table(ctx: Context<SyncAddressLookupTable>) -> Result<()> {
+        instructions::sync_address_lookup_table(ctx)
+    }
+
+    pub fn migrate_wrapper(ctx: Context<MigrateWrapper>) -> Result<()> {
+        instructions::migrate_wrapper(ctx)
+    }
+
+    pub fn set_wrapper_metadata(
+        ctx: Context<SetWrapperMetadata>,
+        args: SetWrapperMetadataArgs,
+    ) -> Result<()> {
+        instructions::set_wrapper_metadata(ctx, args)
+    }
+
+    pub fn set_owner_allowlist(
+        ctx: Context<SetOwnerAllowlist>,
+        allowed_owners: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_owner_allowlist(ctx, allowed_owners)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    pub fn grant_delegate(ctx: Context<GrantDelegate>, args: GrantDelegateArgs) -> Result<()> {
+        instructions::grant_delegate(ctx, args)
+    }
+
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>, delegate: Pubkey) -> Result<()> {
+        instructions::revoke_delegate(ctx, delegate)
+    }
+
+    pub fn create_lut_set(ctx: Context<CreateLutSet>, id: u64) -> Result<()> {
+        instructions::create_lut_set(ctx, id)
+    }
+
+    pub fn add_lut_set_member(ctx: Context<AddLutSetMember>) -> Result<()> {
+        instructions::add_lut_set_member(ctx)
+    }
+
+    pub fn remove_lut_set_member(ctx: Context<RemoveLutSetMember>, member: Pubkey) -> Result<()> {
+        instructions::remove_lut_set_member(ctx, member)
+    }
+
+    pub fn extend_lut_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExtendLutSet<'info>>,
+        args: ExtendLutSetArgs,
+    ) -> Result<()> {
+        instructions::extend_lut_set(ctx, args)
+    }
+
+    pub fn initialize_lut_registry(
+        ctx: Context<InitializeLutRegistry>,
+        next_id: u64,
+    ) -> Result<()> {
+        instructions::initialize_lut_registry(ctx, next_id)
+    }
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        args: InitializeConfigArgs,
+    ) -> Result<()> {
+        instructions::initialize_config(ctx, args)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        instructions::update_config(ctx, args)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused(ctx, paused)
+    }
 }
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// Program-wide settings governed by a single admin.
+///
+/// A singleton PDA derived from `["Config"]`. Instructions that are throttled or
+/// limited read their parameters from here rather than from compile-time constants.
+#[account]
+pub struct Config {
+    /// PDA bump seed for address derivation.
+    pub bump: u8,
+    /// The key allowed to call `update_config`.
+    pub admin: Pubkey,
+    /// A key that can pause the program but not unpause it. `Pubkey::default()` for none.
+    pub guardian: Pubkey,
+    /// Slots to wait after a write before a LUT is ready and can be extended again.
+    pub cooldown_slots: u64,
+    /// Maximum live LUTs a single signer may hold; 0 means unlimited.
+    pub max_luts_per_signer: u64,
+    /// When true, create and extend are rejected. Deactivate and close keep working.
+    pub paused: bool,
+    /// Receives protocol fees. Must be set whenever either fee is non-zero.
+    pub treasury: Pubkey,
+    /// Flat fee in lamports charged on `create_address_lookup_table`.
+    pub create_fee_lamports: u64,
+    /// Fee in lamports charged per address actually written to a LUT.
+    pub extend_fee_lamports_per_address: u64,
+}
+
+impl Config {
+    pub const SEED: &'static str = "Config";
+
+    pub const SIZE: usize = 8 // discriminator
+        + size_of::<u8>() // bump
+        + size_of::<Pubkey>() // admin
+        + size_of::<Pubkey>() // guardian
+        + size_of::<u64>() // cooldown_slots
+        + size_of::<u64>() // max_luts_per_signer
+        + size_of::<bool>() // paused
+        + size_of::<Pubkey>() // treasury
+        + size_of::<u64>() // create_fee_lamports
+        + size_of::<u64>(); // extend_fee_lamports_per_address
+
+    /// Returns true if `key` may set `paused` to the given value. The admin can pause
+    /// and unpause; the guardian can only pause.
+    pub fn can_set_paused(&self, key: &Pubkey, paused: bool) -> bool {
+        *key == self.admin || (paused && *key == self.guardian)
+    }
+
+    /// Returns true if a signer that already holds `live_luts` wrappers may create another.
+    pub fn allows_another_lut(&self, live_luts: u64) -> bool {
+        self.max_luts_per_signer == 0 || live_luts < self.max_luts_per_signer
+    }
+
+    /// Returns the fee charged for writing `count` new addresses to a LUT.
+    pub fn extend_fee(&self, count: usize) -> u64 {
+        self.extend_fee_lamports_per_address
+            .saturating_mul(count as u64)
+    }
+
+    /// Returns true if fees can be collected: either no fee is set or a treasury is.
+    pub fn has_valid_fees(&self) -> bool {
+        (self.create_fee_lamports == 0 && self.extend_fee_lamports_per_address == 0)
+            || self.treasury != Pubkey::default()
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// Number of live wrappers a signer has created, checked against `max_luts_per_signer`.
+///
+/// Derived from `["LutCounter", signer]` and created by the signer's first create. Every
+/// create increments `live_luts` and closing a counted wrapper decrements it, so closing
+/// a LUT frees up room for a new one. Wrappers created before the counter existed are not
+/// counted.
+#[account]
+pub struct LutCounter {
+    /// PDA bump seed for address derivation.
+    pub bump: u8,
+    /// The signer whose wrappers are counted. Matches the wrappers' `creator`.
+    pub signer: Pubkey,
+    /// Counted wrappers that have not been closed.
+    pub live_luts: u64,
+}
+
+impl LutCounter {
+    pub const SEED: &'static str = "LutCounter";
+
+    pub const SIZE: usize = 8 // discriminator
+        + size_of::<u8>() // bump
+        + size_of::<Pubkey>() // signer
+        + size_of::<u64>(); // live_luts
+}
@@ -0,0 +1,48 @@
+use crate::error::LutError;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// Per-signer id allocator and index of live wrappers.
+///
+/// Derived from `["LutRegistry", signer]`. The registry does not pick ids itself: creates
+/// that pass it supply their own id, which must be no lower than `next_id`, skipping any
+/// that were already taken without it. Closes prune the id again, so `live_ids` lists every
+/// wrapper the signer created through it that still exists. The account grows as ids are
+/// added and is never shrunk.
+#[account]
+pub struct LutRegistry {
+    /// PDA bump seed for address derivation.
+    pub bump: u8,
+    /// The signer whose wrappers are indexed. Matches the wrappers' `creator`.
+    pub signer: Pubkey,
+    /// The lowest id the next registered create may use.
+    pub next_id: u64,
+    /// Ids of registered wrappers that have not been closed, in creation order.
+    pub live_ids: Vec<u64>,
+}
+
+impl LutRegistry {
+    pub const SEED: &'static str = "LutRegistry";
+
+    /// Returns the account size needed to hold `live_ids` entries.
+    pub fn space(live_ids: usize) -> usize {
+        8 // discriminator
+            + size_of::<u8>() // bump
+            + size_of::<Pubkey>() // signer
+            + size_of::<u64>() // next_id
+            + 4 // live_ids length prefix
+            + size_of::<u64>() * live_ids // live_ids
+    }
+
+    /// Records `id` as live and moves `next_id` past it.
+    pub fn allocate(&mut self, id: u64) -> Result<()> {
+        self.next_id = id.checked_add(1).ok_or(LutError::LutIdOverflow)?;
+        self.live_ids.push(id);
+        Ok(())
+    }
+
+    /// Removes `id` from the live list.
+    pub fn release(&mut self, id: u64) {
+        self.live_ids.retain(|live_id| *live_id != id);
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// An ordered group of wrapped LUTs owned by one signer.
+///
+/// Derived from `["LutSet", signer, id]`. Extending the set dedupes against every usable
+/// member and fills members in order, so a product that needs more than 256 addresses
+/// can treat several LUTs as one.
+#[account]
+pub struct LutSet {
+    /// PDA bump seed for address derivation.
+    pub bump: u8,
+    /// The owner of the set. Every member must be owned by the same key.
+    pub signer: Pubkey,
+    /// User-defined identifier allowing multiple sets per signer.
+    pub id: u64,
+    /// Number of used slots in `members`.
+    pub member_count: u8,
+    /// `UserAddressLookupTable` wrappers in fill order. Unused slots hold `Pubkey::default()`.
+    pub members: [Pubkey; LutSet::MAX_MEMBERS],
+}
+
+impl LutSet {
+    pub const SEED: &'static str = "LutSet";
+
+    /// Maximum number of wrappers in a set.
+    pub const MAX_MEMBERS: usize = 8;
+
+    pub const SIZE: usize = 8 // discriminator
+        + size_of::<u8>() // bump
+        + size_of::<Pubkey>() // signer
+        + size_of::<u64>() // id
+        + size_of::<u8>() // member_count
+        + size_of::<Pubkey>() * Self::MAX_MEMBERS; // members
+
+    /// Returns the used member slots in fill order.
+    pub fn members(&self) -> &[Pubkey] {
+        &self.members[..self.member_count as usize]
+    }
+
+    /// Returns true if no more members can be added.
+    pub fn is_full(&self) -> bool {
+        self.member_count as usize >= Self::MAX_MEMBERS
+    }
+
+    /// Returns true if `wrapper` is already a member.
+    pub fn contains(&self, wrapper: &Pubkey) -> bool {
+        self.members().contains(wrapper)
+    }
+
+    /// Appends `wrapper` to the end of the fill order and returns its index.
+    pub fn push(&mut self, wrapper: Pubkey) -> u8 {
+        let index = self.member_count;
+        self.members[index as usize] = wrapper;
+        self.member_count += 1;
+        index
+    }
+
+    /// Removes `wrapper`, shifting later members forward, and returns the index it had.
+    pub fn remove(&mut self, wrapper: &Pubkey) -> Option<u8> {
+        let index = self.members().iter().position(|member| member == wrapper)?;
+        let count = self.member_count as usize;
+        self.members.copy_within(index + 1..count, index);
+        self.members[count - 1] = Pubkey::default();
+        self.member_count -= 1;
+        Some(index as u8)
+    }
+}
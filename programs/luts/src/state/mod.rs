@@ -1 +1,5 @@
+pub mod config;
+pub mod lut_counter;
+pub mod lut_registry;
+pub mod lut_set;
 pub mod user_address_lookup_table;
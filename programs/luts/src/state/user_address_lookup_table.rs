@@ -1,33 +1,231 @@
+use crate::error::LutError;
+use anchor_lang::prelude::borsh;
 use anchor_lang::prelude::*;
+use std::io::Read;
 use std::mem::size_of;
 
 /// A PDA wrapper account that tracks ownership and state of an underlying Address Lookup Table.
 ///
-/// This account is derived using seeds `["UserAddressLookupTable", signer, id]` and serves as
+/// This account is derived using seeds `["UserAddressLookupTable", creator, id]`, or
+/// `["UserAddressLookupTable", creator, namespace]` for namespaced wrappers, and serves as
 /// the authority for the native LUT, enabling program-controlled management with additional
 /// features like deduplication and cooldown enforcement.
-#[account]
+///
+/// Wrappers created before `version` existed use the legacy layout, which ends after
+/// `last_updated_slot`. Both layouts deserialize, with the missing fields defaulted, so
+/// read-only instructions and closes work on either. A legacy wrapper can only be written
+/// back while none of the fields it lacks have changed; anything else requires
+/// `migrate_wrapper` first.
+#[derive(AnchorSerialize, Clone, PartialEq)]
 pub struct UserAddressLookupTable {
     /// PDA bump seed for address derivation.
     pub bump: u8,
-    /// The owner/authority who can modify this LUT.
+    /// The current owner/authority who can modify this LUT. Changes on `accept_authority`.
     pub signer: Pubkey,
-    /// Number of addresses added through this wrapper (may differ from LUT if extended externally).
+    /// Number of addresses in the LUT, kept up to date by extends. `sync_address_lookup_table`
+    /// re-derives it from the native table.
     pub size: u64,
-    /// User-defined identifier allowing multiple LUTs per signer.
+    /// User-defined identifier allowing multiple LUTs per signer. 0 for namespaced wrappers.
     pub id: u64,
     /// The underlying native Address Lookup Table address.
     pub address_lookup_table: Pubkey,
     /// Slot of last modification, used to enforce the cooldown period before the LUT is usable.
     pub last_updated_slot: u64,
+    /// The original signer baked into the PDA seeds. Never changes, even after an authority transfer.
+    pub creator: Pubkey,
+    /// Authority proposed via `propose_authority`, or `Pubkey::default()` when none is pending.
+    pub pending_authority: Pubkey,
+    /// Keys allowed to extend the LUT on the owner's behalf. Empty slots hold `Pubkey::default()`.
+    pub delegates: [LutDelegate; UserAddressLookupTable::MAX_DELEGATES],
+    /// Lifecycle state of the underlying LUT, updated by freeze and deactivate.
+    pub status: LutStatus,
+    /// Slot at which the LUT was deactivated, or 0 while it has not been.
+    pub deactivated_slot: u64,
+    /// While true the LUT is still being filled and can be extended back-to-back,
+    /// ignoring the cooldown. Cleared by `mark_in_use`.
+    pub burst_fill: bool,
+    /// Slot from which the LUT's latest addresses can be relied on in transactions.
+    /// Clients and CPI callers should check this before using the LUT.
+    pub ready_at_slot: u64,
+    /// The native LUT retired by the last `rotate_address_lookup_table`, kept until it is
+    /// closed. `Pubkey::default()` when there is none.
+    pub previous_address_lookup_table: Pubkey,
+    /// Number of times the native LUT has been rotated. Starts at 0.
+    pub generation: u64,
+    /// True if the wrapper's id was allocated by the creator's `LutRegistry`, which must
+    /// then be passed on close so the id can be pruned.
+    pub registered: bool,
+    /// Account layout version. 0 for wrappers read from the legacy layout.
+    pub version: u8,
+    /// Free-form name for telling wrappers apart, at most `MAX_LABEL_LEN` bytes. Empty when unset.
+    pub label: String,
+    /// Link to off-chain metadata, at most `MAX_METADATA_URI_LEN` bytes. Empty when unset.
+    pub metadata_uri: String,
+    /// Seed used in place of `id` by wrappers created with
+    /// `create_address_lookup_table_with_namespace`. All zeroes for id-based wrappers.
+    pub namespace: [u8; 32],
+    /// Programs that must own every address added by `extend_address_lookup_table`. Only
+    /// the first `allowed_owner_count` entries are used; the rest hold `Pubkey::default()`.
+    pub allowed_owners: [Pubkey; UserAddressLookupTable::MAX_ALLOWED_OWNERS],
+    /// Number of entries in `allowed_owners` in use. 0 means any owner is allowed.
+    pub allowed_owner_count: u8,
+    /// True if the wrapper is included in the creator's `LutCounter`, which must then be
+    /// passed on close so the count can be decremented.
+    pub counted: bool,
+}
+
+impl AnchorDeserialize for UserAddressLookupTable {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let bump = u8::deserialize_reader(reader)?;
+        let signer = Pubkey::deserialize_reader(reader)?;
+        let size = u64::deserialize_reader(reader)?;
+        let id = u64::deserialize_reader(reader)?;
+        let address_lookup_table = Pubkey::deserialize_reader(reader)?;
+        let last_updated_slot = u64::deserialize_reader(reader)?;
+
+        let mut next = [0u8; 1];
+        if reader.read(&mut next)? == 0 {
+            return Ok(Self::from_legacy(
+                bump,
+                signer,
+                size,
+                id,
+                address_lookup_table,
+                last_updated_slot,
+            ));
+        }
+        let reader = &mut next.as_slice().chain(reader);
+        Ok(Self {
+            bump,
+            signer,
+            size,
+            id,
+            address_lookup_table,
+            last_updated_slot,
+            creator: Pubkey::deserialize_reader(reader)?,
+            pending_authority: Pubkey::deserialize_reader(reader)?,
+            delegates: <[LutDelegate; Self::MAX_DELEGATES]>::deserialize_reader(reader)?,
+            status: LutStatus::deserialize_reader(reader)?,
+            deactivated_slot: u64::deserialize_reader(reader)?,
+            burst_fill: bool::deserialize_reader(reader)?,
+            ready_at_slot: u64::deserialize_reader(reader)?,
+            previous_address_lookup_table: Pubkey::deserialize_reader(reader)?,
+            generation: u64::deserialize_reader(reader)?,
+            registered: bool::deserialize_reader(reader)?,
+            version: u8::deserialize_reader(reader)?,
+            label: String::deserialize_reader(reader)?,
+            metadata_uri: String::deserialize_reader(reader)?,
+            namespace: <[u8; 32]>::deserialize_reader(reader)?,
+            allowed_owners: <[Pubkey; Self::MAX_ALLOWED_OWNERS]>::deserialize_reader(reader)?,
+            allowed_owner_count: u8::deserialize_reader(reader)?,
+            counted: bool::deserialize_reader(reader)?,
+        })
+    }
+}
+
+// Equivalent of what `#[account]` generates, except that legacy wrappers (whose accounts
+// are too small for the current layout) are written back in the legacy layout, and refused
+// with a clear error once a field outside it has changed.
+impl Discriminator for UserAddressLookupTable {
+    // sha256("account:UserAddressLookupTable")[..8], as `#[account]` would derive.
+    const DISCRIMINATOR: &'static [u8] = &[28, 183, 129, 166, 216, 32, 90, 100];
+}
+
+impl Owner for UserAddressLookupTable {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl AccountSerialize for UserAddressLookupTable {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        require!(
+            self.is_current() || self.fits_legacy_layout(),
+            LutError::WrapperNeedsMigration
+        );
+        if writer.write_all(Self::DISCRIMINATOR).is_err() {
+            return Err(ErrorCode::AccountDidNotSerialize.into());
+        }
+        let serialized = if self.is_current() {
+            AnchorSerialize::serialize(self, writer)
+        } else {
+            (
+                self.bump,
+                self.signer,
+                self.size,
+                self.id,
+                self.address_lookup_table,
+                self.last_updated_slot,
+            )
+                .serialize(writer)
+        };
+        serialized.map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl AccountDeserialize for UserAddressLookupTable {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        if buf.len() < Self::DISCRIMINATOR.len() {
+            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        if &buf[..Self::DISCRIMINATOR.len()] != Self::DISCRIMINATOR {
+            return Err(error!(ErrorCode::AccountDiscriminatorMismatch)
+                .with_account_name("UserAddressLookupTable"));
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data: &[u8] = &buf[Self::DISCRIMINATOR.len()..];
+        AnchorDeserialize::deserialize(&mut data)
+            .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+/// Lifecycle state of a wrapped LUT.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LutStatus {
+    /// The LUT can be extended, frozen or deactivated.
+    #[default]
+    Active,
+    /// The LUT is permanently immutable.
+    Frozen,
+    /// The LUT is deactivated and can be closed once the native cooldown has passed.
+    Deactivated,
+}
+
+/// A key granted permission to extend a wrapped LUT without holding the owner's key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LutDelegate {
+    /// The delegate's pubkey, or `Pubkey::default()` for an unused slot.
+    pub key: Pubkey,
+    /// Slot at which the grant stops being valid; 0 means it never expires.
+    pub expires_at_slot: u64,
+}
+
+impl LutDelegate {
+    pub const SIZE: usize = size_of::<Pubkey>() // key
+        + size_of::<u64>(); // expires_at_slot
+
+    /// Returns true if this slot does not hold a grant.
+    pub fn is_empty(&self) -> bool {
+        self.key == Pubkey::default()
+    }
+
+    /// Returns true if the grant exists and has not expired at `current_slot`.
+    pub fn is_active(&self, current_slot: u64) -> bool {
+        !self.is_empty() && (self.expires_at_slot == 0 || current_slot < self.expires_at_slot)
+    }
 }
 
 impl UserAddressLookupTable {
     pub const SEED: &'static str = "UserAddressLookupTable";
-    /// Minimum slots to wait after extending before the LUT is usable in transactions.
-    pub const COOLDOWN_SLOTS: u64 = 15;
     /// Maximum addresses a single LUT can hold.
     pub const MAX_ADDRESSES: usize = 256;
+    /// Maximum number of delegated extenders per wrapper.
+    pub const MAX_DELEGATES: usize = 4;
+    /// Maximum number of programs in the owner allowlist.
+    pub const MAX_ALLOWED_OWNERS: usize = 4;
 
     pub const SIZE: usize = 8 // discriminator
         + size_of::<u8>() // bump
@@ -35,19 +233,209 @@ impl UserAddressLookupTable {
         + size_of::<u64>() // id
         + size_of::<u64>() // size
         + size_of::<Pubkey>() // address_lookup_table
+        + size_of::<u64>() // last_updated_slot
+        + size_of::<Pubkey>() // creator
+        + size_of::<Pubkey>() // pending_authority
+        + LutDelegate::SIZE * Self::MAX_DELEGATES // delegates
+        + size_of::<u8>() // status
+        + size_of::<u64>() // deactivated_slot
+        + size_of::<bool>() // burst_fill
+        + size_of::<u64>() // ready_at_slot
+        + size_of::<Pubkey>() // previous_address_lookup_table
+        + size_of::<u64>() // generation
+        + size_of::<bool>() // registered
+        + size_of::<u8>() // version
+        + 4 + Self::MAX_LABEL_LEN // label
+        + 4 + Self::MAX_METADATA_URI_LEN // metadata_uri
+        + 32 // namespace
+        + size_of::<Pubkey>() * Self::MAX_ALLOWED_OWNERS // allowed_owners
+        + size_of::<u8>() // allowed_owner_count
+        + size_of::<bool>(); // counted
+
+    /// Size of the legacy layout, which has no `version` and ends after `last_updated_slot`.
+    pub const LEGACY_SIZE: usize = 8 // discriminator
+        + size_of::<u8>() // bump
+        + size_of::<Pubkey>() // signer
+        + size_of::<u64>() // size
+        + size_of::<u64>() // id
+        + size_of::<Pubkey>() // address_lookup_table
         + size_of::<u64>(); // last_updated_slot
 
+    /// Layout version written by this program.
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Maximum length of `label` in bytes.
+    pub const MAX_LABEL_LEN: usize = 32;
+    /// Maximum length of `metadata_uri` in bytes.
+    pub const MAX_METADATA_URI_LEN: usize = 200;
+    /// Cooldown the legacy program applied after every write.
+    const LEGACY_COOLDOWN_SLOTS: u64 = 15;
+
+    /// Builds a wrapper from the fields of the legacy layout, defaulting the rest. Legacy
+    /// wrappers could not change hands, so the signer is the creator.
+    fn from_legacy(
+        bump: u8,
+        signer: Pubkey,
+        size: u64,
+        id: u64,
+        address_lookup_table: Pubkey,
+        last_updated_slot: u64,
+    ) -> Self {
+        Self {
+            bump,
+            signer,
+            size,
+            id,
+            address_lookup_table,
+            last_updated_slot,
+            creator: signer,
+            pending_authority: Pubkey::default(),
+            delegates: [LutDelegate::default(); Self::MAX_DELEGATES],
+            status: LutStatus::Active,
+            deactivated_slot: 0,
+            burst_fill: false,
+            ready_at_slot: last_updated_slot.saturating_add(Self::LEGACY_COOLDOWN_SLOTS),
+            previous_address_lookup_table: Pubkey::default(),
+            generation: 0,
+            registered: false,
+            version: 0,
+            label: String::new(),
+            metadata_uri: String::new(),
+            namespace: [0; 32],
+            allowed_owners: [Pubkey::default(); Self::MAX_ALLOWED_OWNERS],
+            allowed_owner_count: 0,
+            counted: false,
+        }
+    }
+
+    /// Returns true if every field missing from the legacy layout still holds its legacy
+    /// default, so the wrapper can be written back in that layout.
+    fn fits_legacy_layout(&self) -> bool {
+        *self
+            == Self::from_legacy(
+                self.bump,
+                self.signer,
+                self.size,
+                self.id,
+                self.address_lookup_table,
+                self.last_updated_slot,
+            )
+    }
+
     /// Returns true if the cooldown period has passed and the LUT is ready for use.
     pub fn is_ready(&self, current_slot: u64) -> bool {
-        current_slot >= self.last_updated_slot.saturating_add(Self::COOLDOWN_SLOTS)
+        current_slot >= self.ready_at_slot
+    }
+
+    /// Returns true if the LUT may be extended at `current_slot`. LUTs in burst-fill
+    /// mode skip the cooldown since nothing is using them yet.
+    pub fn can_write(&self, current_slot: u64) -> bool {
+        self.burst_fill || self.is_ready(current_slot)
+    }
+
+    /// Records a write at `current_slot` and pushes `ready_at_slot` out by `cooldown_slots`.
+    pub fn touch(&mut self, current_slot: u64, cooldown_slots: u64) {
+        self.last_updated_slot = current_slot;
+        self.ready_at_slot = current_slot.saturating_add(cooldown_slots);
+    }
+
+    /// Returns true if the LUT has been frozen.
+    pub fn is_frozen(&self) -> bool {
+        self.status == LutStatus::Frozen
+    }
+
+    /// Returns true if the LUT has been deactivated.
+    pub fn is_deactivated(&self) -> bool {
+        self.status == LutStatus::Deactivated
+    }
+
+    /// Returns true if an authority transfer has been proposed and not yet accepted.
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// Returns true if a rotated-out LUT is still waiting to be closed.
+    pub fn has_previous_lookup_table(&self) -> bool {
+        self.previous_address_lookup_table != Pubkey::default()
+    }
+
+    /// Returns true if `key` holds a grant that is still valid at `current_slot`.
+    pub fn is_active_delegate(&self, key: &Pubkey, current_slot: u64) -> bool {
+        self.delegates
+            .iter()
+            .any(|delegate| delegate.key == *key && delegate.is_active(current_slot))
+    }
+
+    /// Returns true if `key` is the owner or an active delegate.
+    pub fn can_extend(&self, key: &Pubkey, current_slot: u64) -> bool {
+        self.signer == *key || self.is_active_delegate(key, current_slot)
+    }
+
+    /// Returns the programs in the owner allowlist, empty if none is set.
+    pub fn owner_allowlist(&self) -> &[Pubkey] {
+        &self.allowed_owners[..self.allowed_owner_count as usize]
+    }
+
+    /// Returns true if an owner allowlist is set.
+    pub fn has_owner_allowlist(&self) -> bool {
+        self.allowed_owner_count > 0
+    }
+
+    /// Returns true if an address owned by `owner` may be added: no allowlist is set or
+    /// it contains `owner`.
+    pub fn allows_owner(&self, owner: &Pubkey) -> bool {
+        !self.has_owner_allowlist() || self.owner_allowlist().contains(owner)
+    }
+
+    /// Returns true if the wrapper is derived from a namespace rather than an id.
+    pub fn is_namespaced(&self) -> bool {
+        self.namespace != [0; 32]
+    }
+
+    /// Returns the seed following `creator` in the PDA derivation: the namespace for
+    /// namespaced wrappers, otherwise the little-endian `id`.
+    pub fn id_seed(&self) -> Vec<u8> {
+        if self.is_namespaced() {
+            self.namespace.to_vec()
+        } else {
+            self.id.to_le_bytes().to_vec()
+        }
+    }
+
+    /// Returns true if the wrapper uses the current layout and can be written back.
+    pub fn is_current(&self) -> bool {
+        self.version == Self::CURRENT_VERSION
+    }
+
+    /// Sets `label` and `metadata_uri`, leaving either unchanged when `None`. An empty
+    /// string clears the value.
+    pub fn set_metadata(
+        &mut self,
+        label: Option<String>,
+        metadata_uri: Option<String>,
+    ) -> Result<()> {
+        if let Some(label) = label {
+            require!(
+                label.len() <= Self::MAX_LABEL_LEN,
+                LutError::WrapperMetadataTooLong
+            );
+            self.label = label;
+        }
+        if let Some(metadata_uri) = metadata_uri {
+            require!(
+                metadata_uri.len() <= Self::MAX_METADATA_URI_LEN,
+                LutError::WrapperMetadataTooLong
+            );
+            self.metadata_uri = metadata_uri;
+        }
+        Ok(())
     }
 
     /// Returns the PDA seeds for signing CPIs.
     pub fn seeds(&self) -> Vec<Vec<u8>> {
         vec![
             Self::SEED.as_bytes().to_vec(),
-            self.signer.to_bytes().to_vec(),
-            self.id.to_le_bytes().to_vec(),
+            self.creator.to_bytes().to_vec(),
+            self.id_seed(),
             vec![self.bump],
         ]
     }
@@ -1,10 +1,31 @@
-use crate::codama_rust_luts::instructions::CreateAddressLookupTableBuilder;
-use crate::common::pda::{derive_address_lookup_table, get_user_address_lookup_table_pda};
+use crate::codama_rust_luts::instructions::{
+    AddLutSetMemberBuilder, CreateAddressLookupTableBuilder, CreateLutSetBuilder,
+    InitializeConfigBuilder, RotateAddressLookupTableBuilder,
+};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda, get_lut_set_pda,
+    get_program_data_address, get_user_address_lookup_table_pda, BPF_LOADER_UPGRADEABLE_ID,
+};
 use crate::LUTS_ID;
 use mollusk_helper::{MolluskContextHelper, ADDRESS_LOOKUP_TABLE_PROGRAM_ID};
+use solana_account::Account;
 use solana_pubkey::Pubkey;
 
-pub fn create_context() -> MolluskContextHelper {
+pub const DEFAULT_COOLDOWN_SLOTS: u64 = 15;
+
+/// The upgrade authority recorded in the mocked program data account. It is also the
+/// config admin in contexts built by `create_context`.
+pub fn upgrade_authority() -> Pubkey {
+    Pubkey::new_from_array([7; 32])
+}
+
+/// The pause guardian in contexts built by `create_context`.
+pub fn guardian() -> Pubkey {
+    Pubkey::new_from_array([8; 32])
+}
+
+/// Builds a context with the program loaded but no config account.
+pub fn create_context_without_config() -> MolluskContextHelper {
     let elf = std::fs::read("../../target/deploy/luts.so")
         .expect("Failed to read luts.so - run `anchor build` first");
 
@@ -14,9 +35,80 @@ pub fn create_context() -> MolluskContextHelper {
     let mut ctx = MolluskContextHelper::new(&LUTS_ID, &elf);
     ctx.add_program(&ADDRESS_LOOKUP_TABLE_PROGRAM_ID, &alt_elf);
 
+    let (program_data, _) = get_program_data_address();
+
+    // UpgradeableLoaderState::Program { programdata_address }
+    let mut program_account_data = 2u32.to_le_bytes().to_vec();
+    program_account_data.extend_from_slice(program_data.as_ref());
+    ctx.add_account(
+        &LUTS_ID,
+        Account {
+            lamports: 1_000_000_000,
+            data: program_account_data,
+            owner: BPF_LOADER_UPGRADEABLE_ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+
+    // UpgradeableLoaderState::ProgramData { slot, upgrade_authority_address }
+    let mut program_data_account_data = 3u32.to_le_bytes().to_vec();
+    program_data_account_data.extend_from_slice(&0u64.to_le_bytes());
+    program_data_account_data.push(1);
+    program_data_account_data.extend_from_slice(upgrade_authority().as_ref());
+    ctx.add_account(
+        &program_data,
+        Account {
+            lamports: 1_000_000_000,
+            data: program_data_account_data,
+            owner: BPF_LOADER_UPGRADEABLE_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    ctx
+}
+
+pub fn create_context() -> MolluskContextHelper {
+    let ctx = create_context_without_config();
+    initialize_config(&ctx, &upgrade_authority(), DEFAULT_COOLDOWN_SLOTS, 0);
     ctx
 }
 
+pub fn initialize_config(
+    ctx: &MolluskContextHelper,
+    admin: &Pubkey,
+    cooldown_slots: u64,
+    max_luts_per_signer: u64,
+) {
+    let signer = upgrade_authority();
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (program_data, _) = get_program_data_address();
+    let (config, _) = get_config_pda();
+
+    let instruction = InitializeConfigBuilder::new()
+        .signer(signer)
+        .program_data(program_data)
+        .config(config)
+        .admin(*admin)
+        .guardian(guardian())
+        .cooldown_slots(cooldown_slots)
+        .max_luts_per_signer(max_luts_per_signer)
+        .treasury(Pubkey::default())
+        .create_fee_lamports(0)
+        .extend_fee_lamports_per_address(0)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "InitializeConfig should succeed: {:?}",
+        result
+    );
+}
+
 pub fn create_lut(
     ctx: &mut MolluskContextHelper,
     signer: &Pubkey,
@@ -29,11 +121,14 @@ pub fn create_lut(
     let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(signer, id);
     let (address_lookup_table, _) =
         derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+    let (config, _) = get_config_pda();
 
     let instruction = CreateAddressLookupTableBuilder::new()
         .signer(*signer)
+        .config(config)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
         .recent_slot(recent_slot)
         .id(id)
         .instruction();
@@ -47,3 +142,71 @@ pub fn create_lut(
 
     (user_address_lookup_table, address_lookup_table)
 }
+
+/// Rotates the wrapper onto a new LUT derived from `recent_slot` and returns its address.
+pub fn rotate_lut(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+    address_lookup_table: &Pubkey,
+    recent_slot: u64,
+) -> Pubkey {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (new_address_lookup_table, _) =
+        derive_address_lookup_table(user_address_lookup_table, recent_slot);
+
+    let instruction = RotateAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(*address_lookup_table)
+        .new_address_lookup_table(new_address_lookup_table)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .recent_slot(recent_slot)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "RotateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    new_address_lookup_table
+}
+
+pub fn create_lut_set(ctx: &MolluskContextHelper, signer: &Pubkey, id: u64) -> Pubkey {
+    let (lut_set, _) = get_lut_set_pda(signer, id);
+
+    let instruction = CreateLutSetBuilder::new()
+        .signer(*signer)
+        .lut_set(lut_set)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "CreateLutSet should succeed: {:?}", result);
+
+    lut_set
+}
+
+pub fn add_lut_set_member(
+    ctx: &MolluskContextHelper,
+    signer: &Pubkey,
+    lut_set: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+) {
+    let instruction = AddLutSetMemberBuilder::new()
+        .signer(*signer)
+        .lut_set(*lut_set)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "AddLutSetMember should succeed: {:?}",
+        result
+    );
+}
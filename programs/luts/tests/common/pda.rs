@@ -3,6 +3,12 @@ use mollusk_helper::ADDRESS_LOOKUP_TABLE_PROGRAM_ID;
 use solana_pubkey::Pubkey;
 
 pub const USER_ADDRESS_LOOKUP_TABLE_SEED: &str = "UserAddressLookupTable";
+pub const CONFIG_SEED: &str = "Config";
+pub const LUT_SET_SEED: &str = "LutSet";
+pub const LUT_REGISTRY_SEED: &str = "LutRegistry";
+pub const LUT_COUNTER_SEED: &str = "LutCounter";
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_pubkey::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
 
 pub fn get_user_address_lookup_table_pda(signer: &Pubkey, id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -15,9 +21,46 @@ pub fn get_user_address_lookup_table_pda(signer: &Pubkey, id: u64) -> (Pubkey, u
     )
 }
 
+pub fn get_namespaced_user_address_lookup_table_pda(
+    signer: &Pubkey,
+    namespace: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_ADDRESS_LOOKUP_TABLE_SEED.as_bytes(),
+            signer.as_ref(),
+            namespace,
+        ],
+        &LUTS_ID,
+    )
+}
+
 pub fn derive_address_lookup_table(authority: &Pubkey, recent_slot: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[authority.as_ref(), &recent_slot.to_le_bytes()],
         &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
     )
 }
+
+pub fn get_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED.as_bytes()], &LUTS_ID)
+}
+
+pub fn get_program_data_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LUTS_ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID)
+}
+
+pub fn get_lut_set_pda(signer: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LUT_SET_SEED.as_bytes(), signer.as_ref(), &id.to_le_bytes()],
+        &LUTS_ID,
+    )
+}
+
+pub fn get_lut_registry_pda(signer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LUT_REGISTRY_SEED.as_bytes(), signer.as_ref()], &LUTS_ID)
+}
+
+pub fn get_lut_counter_pda(signer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LUT_COUNTER_SEED.as_bytes(), signer.as_ref()], &LUTS_ID)
+}
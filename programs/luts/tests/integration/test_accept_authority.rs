@@ -0,0 +1,156 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    AcceptAuthorityBuilder, ExtendAddressLookupTableBuilder, ProposeAuthorityBuilder,
+};
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+fn propose_authority(
+    ctx: &MolluskContextHelper,
+    signer: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+    new_authority: &Pubkey,
+) {
+    let instruction = ProposeAuthorityBuilder::new()
+        .signer(*signer)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .new_authority(*new_authority)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "ProposeAuthority should succeed: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_accept_authority_rejects_without_proposal() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&new_authority, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = AcceptAuthorityBuilder::new()
+        .signer(new_authority)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "AcceptAuthority without a proposal should fail with NotPendingAuthority"
+    );
+}
+
+#[test]
+fn test_accept_authority_rejects_wrong_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    propose_authority(&ctx, &signer, &user_address_lookup_table, &new_authority);
+
+    let instruction = AcceptAuthorityBuilder::new()
+        .signer(other)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "AcceptAuthority from a key other than the pending authority should fail"
+    );
+}
+
+#[test]
+fn test_accept_authority_transfers_control() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&new_authority, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    propose_authority(&ctx, &signer, &user_address_lookup_table, &new_authority);
+
+    let instruction = AcceptAuthorityBuilder::new()
+        .signer(new_authority)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "AcceptAuthority should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.signer, new_authority);
+    assert_eq!(wrapper.creator, signer, "Creator should never change");
+    assert_eq!(wrapper.pending_authority, Pubkey::default());
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Previous authority should no longer be able to extend"
+    );
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(new_authority)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "New authority should be able to extend: {:?}",
+        result
+    );
+}
@@ -0,0 +1,83 @@
+use crate::codama_rust_luts::accounts::LutSet;
+use crate::codama_rust_luts::instructions::AddLutSetMemberBuilder;
+use crate::common::helpers::{add_lut_set_member, create_context, create_lut, create_lut_set};
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_add_lut_set_member_appends_in_order() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (first, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+    let (second, _) = create_lut(&mut ctx, &signer, 1, recent_slot + 5);
+
+    add_lut_set_member(&ctx, &signer, &lut_set, &first);
+    add_lut_set_member(&ctx, &signer, &lut_set, &second);
+
+    let lut_set_account = ctx
+        .get_account(&lut_set)
+        .expect("LutSet account should exist");
+    let lut_set = LutSet::from_bytes(&lut_set_account.data).expect("LutSet should deserialize");
+    assert_eq!(lut_set.member_count, 2);
+    assert_eq!(lut_set.members[0], first);
+    assert_eq!(lut_set.members[1], second);
+}
+
+#[test]
+fn test_add_lut_set_member_fails_for_duplicate() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    add_lut_set_member(&ctx, &signer, &lut_set, &user_address_lookup_table);
+
+    let instruction = AddLutSetMemberBuilder::new()
+        .signer(signer)
+        .lut_set(lut_set)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Adding the same wrapper twice should fail with LutSetMemberExists"
+    );
+}
+
+#[test]
+fn test_add_lut_set_member_fails_for_other_owner() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &other, 0, recent_slot);
+
+    let instruction = AddLutSetMemberBuilder::new()
+        .signer(signer)
+        .lut_set(lut_set)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Adding a wrapper owned by another key should fail"
+    );
+}
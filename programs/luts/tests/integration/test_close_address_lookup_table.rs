@@ -1,7 +1,9 @@
+use crate::codama_rust_luts::accounts::LutCounter;
 use crate::codama_rust_luts::instructions::{
-    CloseAddressLookupTableBuilder, DeactivateAddressLookupTableBuilder,
+    CloseAddressLookupTableBuilder, DeactivateAddressLookupTableBuilder, UpdateConfigBuilder,
 };
-use crate::common::helpers::{create_context, create_lut};
+use crate::common::helpers::{create_context, create_lut, upgrade_authority};
+use crate::common::pda::{get_config_pda, get_lut_counter_pda};
 use mollusk_helper::MolluskContextHelper;
 use solana_pubkey::Pubkey;
 
@@ -42,6 +44,7 @@ fn test_close_fails_without_deactivation() {
         .signer(signer)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
         .instruction();
 
     let result = ctx.process_instruction(&instruction);
@@ -75,6 +78,7 @@ fn test_close_fails_immediately_after_deactivation() {
         .signer(signer)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
         .instruction();
 
     let result = ctx.process_instruction(&instruction);
@@ -112,6 +116,7 @@ fn test_close_succeeds_after_deactivation_period() {
         .signer(signer)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
         .instruction();
 
     let result = ctx.process_instruction(&instruction);
@@ -129,3 +134,147 @@ fn test_close_succeeds_after_deactivation_period() {
         );
     }
 }
+
+#[test]
+fn test_close_sends_rent_to_rent_destination() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let rent_destination = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + 10);
+
+    deactivate_lut(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        &address_lookup_table,
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+
+    let signer_balance_before = ctx.get_balance(&signer).unwrap_or(0);
+    let reclaimable = ctx.get_balance(&address_lookup_table).unwrap_or(0)
+        + ctx.get_balance(&user_address_lookup_table).unwrap_or(0);
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .rent_destination(Some(rent_destination))
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseAddressLookupTable with rent destination should succeed: {:?}",
+        result
+    );
+
+    assert_eq!(
+        ctx.get_balance(&rent_destination).unwrap_or(0),
+        reclaimable,
+        "Rent from both accounts should go to the rent destination"
+    );
+    assert_eq!(
+        ctx.get_balance(&signer).unwrap_or(0),
+        signer_balance_before,
+        "Signer balance should be unchanged"
+    );
+}
+
+#[test]
+fn test_close_counted_lut_requires_counter() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + 10);
+    deactivate_lut(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        &address_lookup_table,
+    );
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CloseAddressLookupTable of a counted LUT without the counter should fail"
+    );
+}
+
+#[test]
+fn test_close_frees_room_under_max_luts_per_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .max_luts_per_signer(1)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    // The limit counts live LUTs, so ids above it are fine.
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, 5, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + 10);
+    deactivate_lut(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        &address_lookup_table,
+    );
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+
+    let lut_counter = get_lut_counter_pda(&signer).0;
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(lut_counter))
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    let counter_account = ctx
+        .get_account(&lut_counter)
+        .expect("LutCounter should exist");
+    let counter =
+        LutCounter::from_bytes(&counter_account.data).expect("LutCounter should deserialize");
+    assert_eq!(counter.live_luts, 0);
+
+    create_lut(&mut ctx, &signer, 6, recent_slot + 600);
+}
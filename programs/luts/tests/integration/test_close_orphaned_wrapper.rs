@@ -0,0 +1,194 @@
+use crate::codama_rust_luts::accounts::LutRegistry;
+use crate::codama_rust_luts::instructions::{
+    CloseOrphanedWrapperBuilder, CreateAddressLookupTableBuilder, InitializeLutRegistryBuilder,
+};
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda, get_lut_registry_pda,
+    get_user_address_lookup_table_pda,
+};
+use solana_account::Account;
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_close_orphaned_wrapper() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    // Simulate the native LUT having been closed out from under the wrapper
+    ctx.add_account(&address_lookup_table, Account::default());
+
+    let wrapper_rent = ctx
+        .get_balance(&user_address_lookup_table)
+        .expect("Wrapper account should exist");
+    let balance_before = ctx.get_balance(&signer).unwrap();
+
+    let instruction = CloseOrphanedWrapperBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseOrphanedWrapper should succeed: {:?}",
+        result
+    );
+
+    let wrapper_lamports = ctx
+        .get_balance(&user_address_lookup_table)
+        .unwrap_or_default();
+    assert_eq!(wrapper_lamports, 0, "Wrapper should be closed");
+    assert_eq!(
+        ctx.get_balance(&signer).unwrap(),
+        balance_before + wrapper_rent,
+        "Wrapper rent should go to the signer"
+    );
+}
+
+#[test]
+fn test_close_orphaned_wrapper_fails_while_lut_exists() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = CloseOrphanedWrapperBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CloseOrphanedWrapper with a live LUT should fail with LutNotOrphaned"
+    );
+}
+
+#[test]
+fn test_close_orphaned_wrapper_wrong_signer_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let wrong_signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&wrong_signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    ctx.add_account(&address_lookup_table, Account::default());
+
+    let instruction = CloseOrphanedWrapperBuilder::new()
+        .signer(wrong_signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CloseOrphanedWrapper with wrong signer should fail"
+    );
+}
+
+#[test]
+fn test_close_orphaned_wrapper_releases_registry_id() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (lut_registry, _) = get_lut_registry_pda(&signer);
+    let instruction = InitializeLutRegistryBuilder::new()
+        .signer(signer)
+        .lut_registry(lut_registry)
+        .next_id(0)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "InitializeLutRegistry should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(&signer, 0);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .lut_registry(Some(lut_registry))
+        .recent_slot(recent_slot)
+        .id(0)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    ctx.add_account(&address_lookup_table, Account::default());
+
+    let instruction = CloseOrphanedWrapperBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CloseOrphanedWrapper of a registered wrapper without the registry should fail"
+    );
+
+    let instruction = CloseOrphanedWrapperBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .lut_registry(Some(lut_registry))
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseOrphanedWrapper with the registry should succeed: {:?}",
+        result
+    );
+
+    let registry_account = ctx
+        .get_account(&lut_registry)
+        .expect("LutRegistry account should exist");
+    let registry =
+        LutRegistry::from_bytes(&registry_account.data).expect("LutRegistry should deserialize");
+    assert!(registry.live_ids.is_empty(), "Id should be released");
+}
@@ -0,0 +1,87 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::ClosePreviousAddressLookupTableBuilder;
+use crate::common::helpers::{create_context, create_lut, rotate_lut};
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_close_previous_fails_without_rotation() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = ClosePreviousAddressLookupTableBuilder::new()
+        .signer(signer)
+        .previous_address_lookup_table(Pubkey::default())
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ClosePreviousAddressLookupTable without a rotation should fail with NoPreviousLut"
+    );
+}
+
+#[test]
+fn test_close_previous_succeeds_after_deactivation_period() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let new_address_lookup_table = rotate_lut(
+        &mut ctx,
+        &signer,
+        &user_address_lookup_table,
+        &address_lookup_table,
+        recent_slot + 10,
+    );
+
+    let instruction = ClosePreviousAddressLookupTableBuilder::new()
+        .signer(signer)
+        .previous_address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ClosePreviousAddressLookupTable right after rotation should fail"
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 20);
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "ClosePreviousAddressLookupTable after deactivation period should succeed: {:?}",
+        result
+    );
+    let lut_account = ctx.get_account(&address_lookup_table);
+    if let Some(account) = lut_account {
+        assert_eq!(
+            account.lamports, 0,
+            "Previous AddressLookupTable account should have zero lamports"
+        );
+    }
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("Wrapper account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("Wrapper should deserialize");
+    assert_eq!(wrapper.address_lookup_table, new_address_lookup_table);
+    assert_eq!(wrapper.previous_address_lookup_table, Pubkey::default());
+}
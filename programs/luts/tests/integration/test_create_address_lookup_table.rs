@@ -1,8 +1,14 @@
+use crate::codama_rust_luts::accounts::{LutCounter, UserAddressLookupTable};
 use crate::codama_rust_luts::instructions::CreateAddressLookupTableBuilder;
 use crate::common::helpers::create_context;
-use crate::common::pda::{derive_address_lookup_table, get_user_address_lookup_table_pda};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
 use solana_pubkey::Pubkey;
 
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
 #[test]
 fn test_create_address_lookup_table() {
     let mut ctx = create_context();
@@ -22,8 +28,10 @@ fn test_create_address_lookup_table() {
 
     let instruction = CreateAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
         .recent_slot(recent_slot)
         .id(id)
         .instruction();
@@ -61,8 +69,10 @@ fn test_create_multiple_luts_same_signer() {
 
         let instruction = CreateAddressLookupTableBuilder::new()
             .signer(signer)
+            .config(get_config_pda().0)
             .address_lookup_table(address_lookup_table)
             .user_address_lookup_table(user_address_lookup_table)
+            .lut_counter(get_lut_counter_pda(&signer).0)
             .recent_slot(recent_slot)
             .id(id)
             .instruction();
@@ -75,6 +85,14 @@ fn test_create_multiple_luts_same_signer() {
             result
         );
     }
+
+    let counter_account = ctx
+        .get_account(&get_lut_counter_pda(&signer).0)
+        .expect("LutCounter should exist");
+    let counter =
+        LutCounter::from_bytes(&counter_account.data).expect("LutCounter should deserialize");
+    assert_eq!(counter.signer, signer);
+    assert_eq!(counter.live_luts, 3);
 }
 
 #[test]
@@ -94,8 +112,10 @@ fn test_create_lut_wrong_address_fails() {
 
     let instruction = CreateAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(wrong_address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
         .recent_slot(recent_slot)
         .id(id)
         .instruction();
@@ -106,3 +126,149 @@ fn test_create_lut_wrong_address_fails() {
         "CreateAddressLookupTable with wrong address should fail"
     );
 }
+
+#[test]
+fn test_create_with_separate_payer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let id: u64 = 0;
+
+    ctx.fund_account(&signer, 1_000_000);
+    ctx.fund_account(&payer, 10_000_000_000);
+
+    ctx.warp_to_slot(100);
+    ctx.warp_to_slot(101);
+    let recent_slot: u64 = 100;
+
+    let (user_address_lookup_table, _bump) = get_user_address_lookup_table_pda(&signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .payer(Some(payer))
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with separate payer should succeed: {:?}",
+        result
+    );
+
+    assert_eq!(
+        ctx.get_balance(&signer),
+        Some(1_000_000),
+        "Signer should not pay for the wrapper or the LUT"
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.signer, signer, "Signer should remain the authority");
+}
+
+#[test]
+fn test_create_with_initial_addresses() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    ctx.warp_to_slot(100);
+    ctx.warp_to_slot(101);
+    let recent_slot: u64 = 100;
+
+    let (user_address_lookup_table, _bump) = get_user_address_lookup_table_pda(&signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let addr1 = Pubkey::new_unique();
+    let addr2 = Pubkey::new_unique();
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .initial_addresses(vec![addr1, addr2, addr1])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with initial addresses should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(
+        wrapper.size, 2,
+        "Duplicate initial addresses should be dropped"
+    );
+
+    let lut_account = ctx
+        .get_account(&address_lookup_table)
+        .expect("AddressLookupTable account should exist");
+    assert_eq!(
+        lut_account.data.len(),
+        LOOKUP_TABLE_META_SIZE + 2 * 32,
+        "AddressLookupTable should hold the initial addresses"
+    );
+}
+
+#[test]
+fn test_create_rejects_too_many_initial_addresses() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    ctx.warp_to_slot(100);
+    ctx.warp_to_slot(101);
+    let recent_slot: u64 = 100;
+
+    let (user_address_lookup_table, _bump) = get_user_address_lookup_table_pda(&signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let addresses: Vec<Pubkey> = (0..257).map(|_| Pubkey::new_unique()).collect();
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .initial_addresses(addresses)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable with more than 256 initial addresses should fail"
+    );
+}
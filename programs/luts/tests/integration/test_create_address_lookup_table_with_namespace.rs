@@ -0,0 +1,185 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableWithNamespaceBuilder, DeactivateAddressLookupTableBuilder,
+    ExtendAddressLookupTableWithArgsBuilder, UpdateConfigBuilder,
+};
+use crate::common::helpers::{
+    create_context, create_lut, upgrade_authority, DEFAULT_COOLDOWN_SLOTS,
+};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_namespaced_user_address_lookup_table_pda,
+};
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+const NAMESPACE: [u8; 32] = [7; 32];
+
+fn create_instruction(signer: &Pubkey, namespace: [u8; 32], recent_slot: u64) -> Instruction {
+    let (user_address_lookup_table, _) =
+        get_namespaced_user_address_lookup_table_pda(signer, &namespace);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    CreateAddressLookupTableWithNamespaceBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
+        .recent_slot(recent_slot)
+        .namespace(namespace)
+        .instruction()
+}
+
+fn create_namespaced_lut(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    recent_slot: u64,
+) -> (Pubkey, Pubkey) {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) =
+        get_namespaced_user_address_lookup_table_pda(signer, &NAMESPACE);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let result = ctx.process_instruction(&create_instruction(signer, NAMESPACE, recent_slot));
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTableWithNamespace should succeed: {:?}",
+        result
+    );
+
+    (user_address_lookup_table, address_lookup_table)
+}
+
+#[test]
+fn test_create_address_lookup_table_with_namespace() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_namespaced_lut(&mut ctx, &signer, recent_slot);
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.namespace, NAMESPACE);
+    assert_eq!(wrapper.id, 0);
+    assert_eq!(wrapper.creator, signer);
+    assert_eq!(wrapper.address_lookup_table, address_lookup_table);
+}
+
+#[test]
+fn test_namespaced_wrapper_accepted_by_other_instructions() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_namespaced_lut(&mut ctx, &signer, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique()])
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extending a namespaced LUT should succeed: {:?}",
+        result
+    );
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Deactivating a namespaced LUT should succeed: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_create_with_same_namespace_twice_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    create_namespaced_lut(&mut ctx, &signer, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + 2);
+    let result = ctx.process_instruction(&create_instruction(&signer, NAMESPACE, recent_slot + 1));
+    assert!(
+        result.is_err(),
+        "Creating a second wrapper in the same namespace should fail"
+    );
+}
+
+#[test]
+fn test_create_with_zero_namespace_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let result = ctx.process_instruction(&create_instruction(&signer, [0; 32], recent_slot));
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTableWithNamespace with a zero namespace should fail with InvalidNamespace"
+    );
+}
+
+#[test]
+fn test_namespaced_create_counts_towards_max_luts_per_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .max_luts_per_signer(1)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + 2);
+    let result = ctx.process_instruction(&create_instruction(&signer, NAMESPACE, recent_slot + 1));
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTableWithNamespace beyond max_luts_per_signer should fail"
+    );
+}
@@ -0,0 +1,49 @@
+use crate::codama_rust_luts::accounts::LutSet;
+use crate::codama_rust_luts::instructions::CreateLutSetBuilder;
+use crate::common::helpers::{create_context, create_lut_set};
+use crate::common::pda::get_lut_set_pda;
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_create_lut_set_starts_empty() {
+    let ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 3;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, id);
+
+    let lut_set_account = ctx
+        .get_account(&lut_set)
+        .expect("LutSet account should exist");
+    let lut_set = LutSet::from_bytes(&lut_set_account.data).expect("LutSet should deserialize");
+    assert_eq!(lut_set.signer, signer);
+    assert_eq!(lut_set.id, id);
+    assert_eq!(lut_set.member_count, 0);
+}
+
+#[test]
+fn test_create_lut_set_fails_for_existing_id() {
+    let ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    create_lut_set(&ctx, &signer, id);
+
+    let instruction = CreateLutSetBuilder::new()
+        .signer(signer)
+        .lut_set(get_lut_set_pda(&signer, id).0)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateLutSet with an id already in use should fail"
+    );
+}
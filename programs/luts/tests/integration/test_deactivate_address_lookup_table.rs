@@ -1,7 +1,15 @@
-use crate::codama_rust_luts::instructions::DeactivateAddressLookupTableBuilder;
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    DeactivateAddressLookupTableBuilder, ExtendAddressLookupTableBuilder,
+};
+use crate::codama_rust_luts::types::LutStatus;
 use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
+use solana_instruction::AccountMeta;
 use solana_pubkey::Pubkey;
 
+const COOLDOWN_SLOTS: u64 = 15;
+
 #[test]
 fn test_deactivate_address_lookup_table() {
     let mut ctx = create_context();
@@ -56,3 +64,66 @@ fn test_deactivate_wrong_signer_fails() {
         "DeactivateAddressLookupTable with wrong signer should fail"
     );
 }
+
+#[test]
+fn test_deactivate_records_status_and_blocks_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "DeactivateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.status, LutStatus::Deactivated);
+    assert_eq!(wrapper.deactivated_slot, recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend on a deactivated LUT should fail with LutDeactivated"
+    );
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Deactivating twice should fail with LutDeactivated"
+    );
+}
@@ -1,9 +1,12 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
 use crate::codama_rust_luts::instructions::ExtendAddressLookupTableBuilder;
 use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
 use solana_instruction::AccountMeta;
 use solana_pubkey::Pubkey;
 
 const COOLDOWN_SLOTS: u64 = 15;
+const LOOKUP_TABLE_META_SIZE: usize = 56;
 
 #[test]
 fn test_extend_rejects_during_cooldown() {
@@ -22,6 +25,7 @@ fn test_extend_rejects_during_cooldown() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -54,6 +58,7 @@ fn test_extend_succeeds_after_cooldown() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -87,6 +92,7 @@ fn test_extend_rejects_only_duplicates() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -99,6 +105,7 @@ fn test_extend_rejects_only_duplicates() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -131,6 +138,7 @@ fn test_extend_filters_duplicates_and_adds_new() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -146,6 +154,7 @@ fn test_extend_filters_duplicates_and_adds_new() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -181,6 +190,7 @@ fn test_size_field_tracks_addresses() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr1, false))
@@ -198,6 +208,7 @@ fn test_size_field_tracks_addresses() {
 
     let instruction = ExtendAddressLookupTableBuilder::new()
         .signer(signer)
+        .config(get_config_pda().0)
         .address_lookup_table(address_lookup_table)
         .user_address_lookup_table(user_address_lookup_table)
         .add_remaining_account(AccountMeta::new_readonly(addr4, false))
@@ -211,3 +222,98 @@ fn test_size_field_tracks_addresses() {
         result
     );
 }
+
+#[test]
+fn test_extend_dedupes_within_batch() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let addr1 = Pubkey::new_unique();
+    let addr2 = Pubkey::new_unique();
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(addr1, false))
+        .add_remaining_account(AccountMeta::new_readonly(addr2, false))
+        .add_remaining_account(AccountMeta::new_readonly(addr1, false))
+        .add_remaining_account(AccountMeta::new_readonly(addr2, false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend with in-batch duplicates should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.size, 2, "In-batch duplicates should be dropped");
+
+    let lut_account = ctx
+        .get_account(&address_lookup_table)
+        .expect("AddressLookupTable account should exist");
+    assert_eq!(
+        lut_account.data.len(),
+        LOOKUP_TABLE_META_SIZE + 2 * 32,
+        "AddressLookupTable should hold each address once"
+    );
+}
+
+#[test]
+fn test_extend_with_separate_payer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&payer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let signer_balance_before = ctx.get_balance(&signer);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .payer(Some(payer))
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend with separate payer should succeed: {:?}",
+        result
+    );
+
+    assert_eq!(
+        ctx.get_balance(&signer),
+        signer_balance_before,
+        "Signer should not pay for the LUT reallocation"
+    );
+}
@@ -0,0 +1,157 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::ExtendAddressLookupTableWithArgsBuilder;
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+#[test]
+fn test_extend_with_args_rejects_during_cooldown() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique()])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend with args during cooldown should fail with LutNotReady"
+    );
+}
+
+#[test]
+fn test_extend_with_args_succeeds_after_cooldown() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique(), Pubkey::new_unique()])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend with args after cooldown should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.size, 2, "Size should track addresses added");
+}
+
+#[test]
+fn test_extend_with_args_dedupes_within_batch_and_against_lut() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let addr1 = Pubkey::new_unique();
+    let addr2 = Pubkey::new_unique();
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![addr1, addr1])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "First extend should succeed: {:?}", result);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS * 2 + 4);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![addr1, addr2, addr2])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend with mix of duplicate and new should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.size, 2, "Duplicates should not be counted");
+}
+
+#[test]
+fn test_extend_with_args_rejects_empty_batch() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend with no addresses should fail with NoNewAddresses"
+    );
+}
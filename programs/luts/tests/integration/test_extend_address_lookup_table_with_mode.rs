@@ -0,0 +1,336 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, ExtendAddressLookupTableWithModeBuilder,
+};
+use crate::codama_rust_luts::types::{ExtendMode, ExtendResult};
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use borsh::BorshDeserialize;
+use mollusk_helper::MolluskContextHelper;
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+fn create_lut_with_addresses(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    id: u64,
+    recent_slot: u64,
+    count: usize,
+) -> (Pubkey, Pubkey) {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .initial_addresses((0..count).map(|_| Pubkey::new_unique()).collect())
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    (user_address_lookup_table, address_lookup_table)
+}
+
+#[test]
+fn test_ensure_with_nothing_new_succeeds_during_cooldown() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let addr = Pubkey::new_unique();
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![addr])
+        .mode(ExtendMode::Ensure)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "First ensure should write: {:?}", result);
+
+    // Still inside the cooldown started by the write above
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![addr, addr])
+        .mode(ExtendMode::Ensure)
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("Ensure with nothing new should succeed during cooldown");
+    let extend_result = ExtendResult::try_from_slice(&result.return_data)
+        .expect("Return data should be an ExtendResult");
+    assert_eq!(
+        extend_result.addresses_added, 0,
+        "Nothing should be written"
+    );
+    assert_eq!(extend_result.total_addresses, 1);
+}
+
+#[test]
+fn test_ensure_with_new_addresses_rejects_during_cooldown() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique()])
+        .mode(ExtendMode::Ensure)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Ensure with new addresses during cooldown should fail with LutNotReady"
+    );
+}
+
+#[test]
+fn test_ensure_with_nothing_new_ignores_unauthorized_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(other)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![])
+        .mode(ExtendMode::Ensure)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Ensure with nothing to add should not need write access: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_strict_mode_with_nothing_new_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![])
+        .mode(ExtendMode::Strict)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Strict mode with nothing new should fail with NoNewAddresses"
+    );
+}
+
+#[test]
+fn test_ensure_returns_addresses_written() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique(), Pubkey::new_unique()])
+        .mode(ExtendMode::Ensure)
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("Ensure after cooldown should succeed");
+    let extend_result = ExtendResult::try_from_slice(&result.return_data)
+        .expect("Return data should be an ExtendResult");
+    assert_eq!(extend_result.addresses_added, 2);
+    assert_eq!(extend_result.total_addresses, 2);
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.size, 2, "Size should track addresses added");
+}
+
+#[test]
+fn test_partial_fill_writes_what_fits_and_reports_rejected() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut_with_addresses(&mut ctx, &signer, id, recent_slot, 200);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let addresses: Vec<Pubkey> = (0..60).map(|_| Pubkey::new_unique()).collect();
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(addresses)
+        .mode(ExtendMode::PartialFill)
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("Partial fill past capacity should succeed");
+    let extend_result = ExtendResult::try_from_slice(&result.return_data)
+        .expect("Return data should be an ExtendResult");
+    assert_eq!(extend_result.addresses_added, 56);
+    assert_eq!(extend_result.total_addresses, 256);
+    assert_eq!(extend_result.addresses_rejected, 4);
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.size, 256, "Size should stop at capacity");
+}
+
+#[test]
+fn test_strict_mode_past_capacity_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut_with_addresses(&mut ctx, &signer, id, recent_slot, 200);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses((0..60).map(|_| Pubkey::new_unique()).collect())
+        .mode(ExtendMode::Strict)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Strict mode past capacity should fail with MaxAddressesExceeded"
+    );
+}
+
+#[test]
+fn test_partial_fill_on_full_lut_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut_with_addresses(&mut ctx, &signer, id, recent_slot, 256);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithModeBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique()])
+        .mode(ExtendMode::PartialFill)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Partial fill with no room should fail with MaxAddressesExceeded"
+    );
+}
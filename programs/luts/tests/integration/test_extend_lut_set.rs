@@ -0,0 +1,264 @@
+use crate::codama_rust_luts::accounts::{LutSet, UserAddressLookupTable};
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, DeactivateAddressLookupTableBuilder, ExtendLutSetBuilder,
+};
+use crate::codama_rust_luts::types::LutSetSpill;
+use crate::common::helpers::{
+    add_lut_set_member, create_context, create_lut_set, DEFAULT_COOLDOWN_SLOTS,
+};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+fn create_member(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    id: u64,
+    recent_slot: u64,
+    initial_addresses: Vec<Pubkey>,
+) -> (Pubkey, Pubkey) {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .initial_addresses(initial_addresses)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    (user_address_lookup_table, address_lookup_table)
+}
+
+fn member_accounts(members: &[(Pubkey, Pubkey)]) -> Vec<AccountMeta> {
+    members
+        .iter()
+        .flat_map(|(wrapper, lut)| {
+            [
+                AccountMeta::new(*wrapper, false),
+                AccountMeta::new(*lut, false),
+            ]
+        })
+        .collect()
+}
+
+fn wrapper_size(ctx: &MolluskContextHelper, user_address_lookup_table: &Pubkey) -> u64 {
+    let wrapper_account = ctx
+        .get_account(user_address_lookup_table)
+        .expect("Wrapper account should exist");
+    UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("Wrapper should deserialize")
+        .size
+}
+
+#[test]
+fn test_extend_lut_set_dedupes_across_members() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+    let first_address = Pubkey::new_unique();
+    let second_address = Pubkey::new_unique();
+    let new_address = Pubkey::new_unique();
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let first = create_member(&mut ctx, &signer, 0, recent_slot, vec![first_address]);
+    let second = create_member(&mut ctx, &signer, 1, recent_slot + 5, vec![second_address]);
+    add_lut_set_member(&ctx, &signer, &lut_set, &first.0);
+    add_lut_set_member(&ctx, &signer, &lut_set, &second.0);
+
+    ctx.warp_to_slot(recent_slot + 5 + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendLutSetBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .lut_set(lut_set)
+        .addresses(vec![
+            first_address,
+            second_address,
+            new_address,
+            new_address,
+        ])
+        .add_remaining_accounts(&member_accounts(&[first, second]))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "ExtendLutSet should succeed: {:?}", result);
+    assert_eq!(wrapper_size(&ctx, &first.0), 2);
+    assert_eq!(wrapper_size(&ctx, &second.0), 1);
+}
+
+#[test]
+fn test_extend_lut_set_ignores_deactivated_member() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+    let first_address = Pubkey::new_unique();
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let first = create_member(&mut ctx, &signer, 0, recent_slot, vec![first_address]);
+    let second = create_member(&mut ctx, &signer, 1, recent_slot + 5, vec![]);
+    add_lut_set_member(&ctx, &signer, &lut_set, &first.0);
+    add_lut_set_member(&ctx, &signer, &lut_set, &second.0);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(first.1)
+        .user_address_lookup_table(first.0)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "DeactivateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 5 + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendLutSetBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .lut_set(lut_set)
+        .addresses(vec![first_address])
+        .add_remaining_accounts(&member_accounts(&[first, second]))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "ExtendLutSet should succeed: {:?}", result);
+    assert_eq!(wrapper_size(&ctx, &first.0), 1);
+    assert_eq!(wrapper_size(&ctx, &second.0), 1);
+}
+
+#[test]
+fn test_extend_lut_set_spills_into_new_member() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+    let spill_slot: u64 = 200;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let initial: Vec<Pubkey> = (0..128).map(|_| Pubkey::new_unique()).collect();
+    let first = create_member(&mut ctx, &signer, 0, recent_slot, initial);
+    add_lut_set_member(&ctx, &signer, &lut_set, &first.0);
+
+    ctx.warp_to_slot(spill_slot);
+    ctx.warp_to_slot(spill_slot + 1);
+
+    let (new_user_address_lookup_table, _) = get_user_address_lookup_table_pda(&signer, 1);
+    let (new_address_lookup_table, _) =
+        derive_address_lookup_table(&new_user_address_lookup_table, spill_slot);
+
+    let instruction = ExtendLutSetBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .lut_set(lut_set)
+        .new_user_address_lookup_table(Some(new_user_address_lookup_table))
+        .new_address_lookup_table(Some(new_address_lookup_table))
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .addresses((0..130).map(|_| Pubkey::new_unique()).collect())
+        .spill(LutSetSpill {
+            id: 1,
+            recent_slot: spill_slot,
+        })
+        .add_remaining_accounts(&member_accounts(&[first]))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "ExtendLutSet should succeed: {:?}", result);
+    assert_eq!(wrapper_size(&ctx, &first.0), 256);
+    assert_eq!(wrapper_size(&ctx, &new_user_address_lookup_table), 2);
+
+    let lut_set_account = ctx
+        .get_account(&lut_set)
+        .expect("LutSet account should exist");
+    let lut_set = LutSet::from_bytes(&lut_set_account.data).expect("LutSet should deserialize");
+    assert_eq!(lut_set.member_count, 2);
+    assert_eq!(lut_set.members[1], new_user_address_lookup_table);
+}
+
+#[test]
+fn test_extend_lut_set_fails_when_full_without_spill() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let initial: Vec<Pubkey> = (0..128).map(|_| Pubkey::new_unique()).collect();
+    let first = create_member(&mut ctx, &signer, 0, recent_slot, initial);
+    add_lut_set_member(&ctx, &signer, &lut_set, &first.0);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendLutSetBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .lut_set(lut_set)
+        .addresses((0..130).map(|_| Pubkey::new_unique()).collect())
+        .add_remaining_accounts(&member_accounts(&[first]))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ExtendLutSet past the last member without a spill should fail with MaxAddressesExceeded"
+    );
+}
+
+#[test]
+fn test_extend_lut_set_fails_without_member_accounts() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let first = create_member(&mut ctx, &signer, 0, recent_slot, Vec::new());
+    add_lut_set_member(&ctx, &signer, &lut_set, &first.0);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendLutSetBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .lut_set(lut_set)
+        .addresses(vec![Pubkey::new_unique()])
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ExtendLutSet without the member accounts should fail with InvalidLutSetMember"
+    );
+}
@@ -0,0 +1,100 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    ExtendAddressLookupTableBuilder, FreezeAddressLookupTableBuilder,
+};
+use crate::codama_rust_luts::types::LutStatus;
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+#[test]
+fn test_freeze_fails_on_empty_lut() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = FreezeAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "FreezeAddressLookupTable on an empty LUT should fail"
+    );
+}
+
+#[test]
+fn test_freeze_blocks_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Extend should succeed: {:?}", result);
+
+    let instruction = FreezeAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "FreezeAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.status, LutStatus::Frozen);
+
+    ctx.warp_to_slot(recent_slot + 2 * (COOLDOWN_SLOTS + 2));
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend on a frozen LUT should fail with LutFrozen"
+    );
+}
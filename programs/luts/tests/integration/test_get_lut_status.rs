@@ -0,0 +1,123 @@
+use crate::codama_rust_luts::instructions::{
+    DeactivateAddressLookupTableBuilder, ExtendAddressLookupTableWithArgsBuilder,
+    GetLutStatusBuilder,
+};
+use crate::codama_rust_luts::types::{LutStatus, LutStatusInfo};
+use crate::common::helpers::{create_context, create_lut, DEFAULT_COOLDOWN_SLOTS};
+use crate::common::pda::get_config_pda;
+use borsh::BorshDeserialize;
+use mollusk_helper::MolluskContextHelper;
+use solana_pubkey::Pubkey;
+
+fn get_lut_status(
+    ctx: &MolluskContextHelper,
+    user_address_lookup_table: &Pubkey,
+    address_lookup_table: &Pubkey,
+) -> LutStatusInfo {
+    let instruction = GetLutStatusBuilder::new()
+        .address_lookup_table(*address_lookup_table)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("GetLutStatus should succeed");
+    LutStatusInfo::try_from_slice(&result.return_data)
+        .expect("Return data should be a LutStatusInfo")
+}
+
+#[test]
+fn test_get_lut_status_reports_cooldown_and_count() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique(), Pubkey::new_unique()])
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Extend should succeed: {:?}", result);
+
+    let status = get_lut_status(&ctx, &user_address_lookup_table, &address_lookup_table);
+    assert!(!status.ready, "LUT should not be ready during cooldown");
+    assert_eq!(status.address_count, 2);
+    assert_eq!(status.status, LutStatus::Active);
+    assert_eq!(status.address_lookup_table, address_lookup_table);
+
+    ctx.warp_to_slot(status.ready_at_slot);
+
+    let status = get_lut_status(&ctx, &user_address_lookup_table, &address_lookup_table);
+    assert!(
+        status.ready,
+        "LUT should be ready once ready_at_slot is reached"
+    );
+}
+
+#[test]
+fn test_get_lut_status_reports_deactivated() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Deactivate should succeed: {:?}", result);
+
+    let status = get_lut_status(&ctx, &user_address_lookup_table, &address_lookup_table);
+    assert!(!status.ready, "Deactivated LUT should not be ready");
+    assert_eq!(status.status, LutStatus::Deactivated);
+    assert_eq!(
+        status.deactivated_slot,
+        recent_slot + DEFAULT_COOLDOWN_SLOTS + 2
+    );
+}
+
+#[test]
+fn test_get_lut_status_rejects_mismatched_lut() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+    let (_, other_address_lookup_table) = create_lut(&mut ctx, &signer, 1, recent_slot + 5);
+
+    let instruction = GetLutStatusBuilder::new()
+        .address_lookup_table(other_address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "GetLutStatus with another wrapper's LUT should fail"
+    );
+}
@@ -0,0 +1,235 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    DeactivateAddressLookupTableBuilder, ExtendAddressLookupTableBuilder, GrantDelegateBuilder,
+};
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+#[test]
+fn test_delegate_can_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&delegate, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = GrantDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(delegate)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "GrantDelegate should succeed: {:?}", result);
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.delegates[0].key, delegate);
+    assert_eq!(wrapper.delegates[0].expires_at_slot, 0);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(delegate)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Delegate should be able to extend: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_non_delegate_cannot_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(other)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend from a non-delegate should fail with UnauthorizedExtender"
+    );
+}
+
+#[test]
+fn test_delegate_cannot_deactivate() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&delegate, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = GrantDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(delegate)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "GrantDelegate should succeed: {:?}", result);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(delegate)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_err(), "Delegate should not be able to deactivate");
+}
+
+#[test]
+fn test_expired_delegate_cannot_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+    let expires_at_slot = recent_slot + COOLDOWN_SLOTS;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&delegate, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = GrantDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(delegate)
+        .expires_at_slot(expires_at_slot)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "GrantDelegate with expiry should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(delegate)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Expired delegate should not be able to extend"
+    );
+}
+
+#[test]
+fn test_grant_rejects_past_expiry() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = GrantDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(Pubkey::new_unique())
+        .expires_at_slot(recent_slot)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "GrantDelegate with a past expiry should fail with InvalidDelegateExpiry"
+    );
+}
+
+#[test]
+fn test_grant_rejects_beyond_max_delegates() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    for _ in 0..4 {
+        let instruction = GrantDelegateBuilder::new()
+            .signer(signer)
+            .user_address_lookup_table(user_address_lookup_table)
+            .delegate(Pubkey::new_unique())
+            .instruction();
+
+        let result = ctx.process_instruction(&instruction);
+        assert!(result.is_ok(), "GrantDelegate should succeed: {:?}", result);
+    }
+
+    let instruction = GrantDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(Pubkey::new_unique())
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "GrantDelegate beyond the limit should fail with MaxDelegatesExceeded"
+    );
+}
@@ -0,0 +1,75 @@
+use crate::codama_rust_luts::accounts::Config;
+use crate::codama_rust_luts::instructions::InitializeConfigBuilder;
+use crate::common::helpers::{
+    create_context, create_context_without_config, guardian, upgrade_authority,
+    DEFAULT_COOLDOWN_SLOTS,
+};
+use crate::common::pda::{get_config_pda, get_program_data_address};
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_initialize_config() {
+    let ctx = create_context();
+
+    let (config, _) = get_config_pda();
+    let config_account = ctx
+        .get_account(&config)
+        .expect("Config account should exist");
+    let config = Config::from_bytes(&config_account.data).expect("Config should deserialize");
+    assert_eq!(config.admin, upgrade_authority());
+    assert_eq!(config.guardian, guardian());
+    assert_eq!(config.cooldown_slots, DEFAULT_COOLDOWN_SLOTS);
+    assert_eq!(config.max_luts_per_signer, 0);
+    assert!(!config.paused);
+}
+
+#[test]
+fn test_initialize_config_rejects_non_upgrade_authority() {
+    let ctx = create_context_without_config();
+
+    let signer = Pubkey::new_unique();
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let instruction = InitializeConfigBuilder::new()
+        .signer(signer)
+        .program_data(get_program_data_address().0)
+        .config(get_config_pda().0)
+        .admin(signer)
+        .guardian(Pubkey::default())
+        .cooldown_slots(DEFAULT_COOLDOWN_SLOTS)
+        .max_luts_per_signer(0)
+        .treasury(Pubkey::default())
+        .create_fee_lamports(0)
+        .extend_fee_lamports_per_address(0)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "InitializeConfig from a key other than the upgrade authority should fail"
+    );
+}
+
+#[test]
+fn test_initialize_config_twice_fails() {
+    let ctx = create_context();
+
+    let instruction = InitializeConfigBuilder::new()
+        .signer(upgrade_authority())
+        .program_data(get_program_data_address().0)
+        .config(get_config_pda().0)
+        .admin(upgrade_authority())
+        .guardian(Pubkey::default())
+        .cooldown_slots(DEFAULT_COOLDOWN_SLOTS)
+        .max_luts_per_signer(0)
+        .treasury(Pubkey::default())
+        .create_fee_lamports(0)
+        .extend_fee_lamports_per_address(0)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "InitializeConfig on an existing config should fail"
+    );
+}
@@ -0,0 +1,318 @@
+use crate::codama_rust_luts::accounts::{LutRegistry, UserAddressLookupTable};
+use crate::codama_rust_luts::instructions::{
+    CloseAddressLookupTableBuilder, CreateAddressLookupTableBuilder,
+    DeactivateAddressLookupTableBuilder, InitializeLutRegistryBuilder, UpdateConfigBuilder,
+};
+use crate::common::helpers::{create_context, create_lut, upgrade_authority};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda, get_lut_registry_pda,
+    get_user_address_lookup_table_pda,
+};
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+fn initialize_lut_registry(ctx: &MolluskContextHelper, signer: &Pubkey, next_id: u64) -> Pubkey {
+    let (lut_registry, _) = get_lut_registry_pda(signer);
+
+    let instruction = InitializeLutRegistryBuilder::new()
+        .signer(*signer)
+        .lut_registry(lut_registry)
+        .next_id(next_id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "InitializeLutRegistry should succeed: {:?}",
+        result
+    );
+
+    lut_registry
+}
+
+fn create_registered_lut(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    lut_registry: &Pubkey,
+    id: u64,
+    recent_slot: u64,
+) -> (Instruction, Pubkey, Pubkey) {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .lut_registry(Some(*lut_registry))
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    (instruction, user_address_lookup_table, address_lookup_table)
+}
+
+fn get_lut_registry(ctx: &MolluskContextHelper, lut_registry: &Pubkey) -> LutRegistry {
+    let lut_registry_account = ctx
+        .get_account(lut_registry)
+        .expect("LutRegistry account should exist");
+    LutRegistry::from_bytes(&lut_registry_account.data).expect("LutRegistry should deserialize")
+}
+
+#[test]
+fn test_create_allocates_ids_from_registry() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_registry = initialize_lut_registry(&ctx, &signer, 5);
+
+    let (instruction, first, _) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 5, recent_slot);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with next_id should succeed: {:?}",
+        result
+    );
+
+    let (instruction, _, _) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 6, recent_slot + 5);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with next_id should succeed: {:?}",
+        result
+    );
+
+    let registry = get_lut_registry(&ctx, &lut_registry);
+    assert_eq!(registry.next_id, 7);
+    assert_eq!(registry.live_ids, vec![5, 6]);
+
+    let wrapper_account = ctx
+        .get_account(&first)
+        .expect("Wrapper account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("Wrapper should deserialize");
+    assert!(wrapper.registered);
+}
+
+#[test]
+fn test_create_with_registry_rejects_id_below_next_id() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_registry = initialize_lut_registry(&ctx, &signer, 5);
+
+    let (instruction, _, _) = create_registered_lut(&mut ctx, &signer, &lut_registry, 3, 100);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable with an id below next_id should fail with LutIdBelowNext"
+    );
+}
+
+#[test]
+fn test_create_with_registry_rejects_max_id() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_registry = initialize_lut_registry(&ctx, &signer, 0);
+
+    let (instruction, _, _) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, u64::MAX, 100);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable with id u64::MAX should fail with LutIdOverflow"
+    );
+
+    let registry = get_lut_registry(&ctx, &lut_registry);
+    assert_eq!(registry.next_id, 0);
+    assert!(registry.live_ids.is_empty());
+}
+
+#[test]
+fn test_create_with_registry_skips_id_taken_without_it() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_registry = initialize_lut_registry(&ctx, &signer, 0);
+    create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    let (instruction, _, _) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 0, recent_slot + 5);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable with an id that is already taken should fail"
+    );
+
+    let (instruction, _, _) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 1, recent_slot + 10);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with the next free id should succeed: {:?}",
+        result
+    );
+
+    let registry = get_lut_registry(&ctx, &lut_registry);
+    assert_eq!(registry.next_id, 2);
+    assert_eq!(registry.live_ids, vec![1]);
+}
+
+#[test]
+fn test_close_prunes_registry() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_registry = initialize_lut_registry(&ctx, &signer, 0);
+    let (instruction, user_address_lookup_table, address_lookup_table) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 0, recent_slot);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with next_id should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 10);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "DeactivateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CloseAddressLookupTable of a registered LUT without the registry should fail"
+    );
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .lut_registry(Some(lut_registry))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseAddressLookupTable with the registry should succeed: {:?}",
+        result
+    );
+
+    let registry = get_lut_registry(&ctx, &lut_registry);
+    assert_eq!(registry.next_id, 1);
+    assert!(registry.live_ids.is_empty());
+}
+
+#[test]
+fn test_closing_registered_lut_frees_room_under_max_luts_per_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .max_luts_per_signer(1)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    let lut_registry = initialize_lut_registry(&ctx, &signer, 0);
+    let (instruction, user_address_lookup_table, address_lookup_table) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 0, recent_slot);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with next_id should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 10);
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "DeactivateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .lut_registry(Some(lut_registry))
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseAddressLookupTable with the registry should succeed: {:?}",
+        result
+    );
+
+    // next_id is now 1, which is not below the limit, but the signer holds no live LUTs.
+    let (instruction, _, _) =
+        create_registered_lut(&mut ctx, &signer, &lut_registry, 1, recent_slot + 600);
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable after closing should succeed: {:?}",
+        result
+    );
+}
@@ -0,0 +1,161 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, ExtendAddressLookupTableBuilder, MarkInUseBuilder,
+};
+use crate::common::helpers::create_context;
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+fn create_burst_fill_lut(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    id: u64,
+    recent_slot: u64,
+) -> (Pubkey, Pubkey) {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .burst_fill(true)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable in burst-fill mode should succeed: {:?}",
+        result
+    );
+
+    (user_address_lookup_table, address_lookup_table)
+}
+
+fn extend_instruction(
+    signer: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+    address_lookup_table: &Pubkey,
+) -> Instruction {
+    ExtendAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(*address_lookup_table)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction()
+}
+
+#[test]
+fn test_burst_fill_allows_back_to_back_extends() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_burst_fill_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction =
+        extend_instruction(&signer, &user_address_lookup_table, &address_lookup_table);
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "First burst-fill extend should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 2);
+
+    let instruction =
+        extend_instruction(&signer, &user_address_lookup_table, &address_lookup_table);
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Second burst-fill extend should succeed without waiting: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert!(wrapper.burst_fill);
+    assert_eq!(wrapper.size, 2);
+    assert_eq!(wrapper.ready_at_slot, recent_slot + 2 + COOLDOWN_SLOTS);
+}
+
+#[test]
+fn test_mark_in_use_restores_cooldown() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_burst_fill_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction =
+        extend_instruction(&signer, &user_address_lookup_table, &address_lookup_table);
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Burst-fill extend should succeed: {:?}",
+        result
+    );
+
+    let instruction = MarkInUseBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "MarkInUse should succeed: {:?}", result);
+
+    ctx.warp_to_slot(recent_slot + 2);
+
+    let instruction =
+        extend_instruction(&signer, &user_address_lookup_table, &address_lookup_table);
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend after MarkInUse should be throttled by the cooldown"
+    );
+
+    let instruction = MarkInUseBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "MarkInUse on a LUT already in use should fail with NotInBurstFill"
+    );
+}
@@ -0,0 +1,279 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    CloseAddressLookupTableBuilder, DeactivateAddressLookupTableBuilder, GetLutStatusBuilder,
+    MigrateWrapperBuilder, ProposeAuthorityBuilder,
+};
+use crate::codama_rust_luts::types::{LutStatus, LutStatusInfo};
+use crate::common::helpers::{create_context, create_lut, DEFAULT_COOLDOWN_SLOTS};
+use borsh::BorshDeserialize;
+use mollusk_helper::MolluskContextHelper;
+use solana_pubkey::Pubkey;
+
+// discriminator, bump, signer, size, id, address_lookup_table, last_updated_slot
+const LEGACY_SIZE: usize = 8 + 1 + 32 + 8 + 8 + 32 + 8;
+// Maximum lengths the program reserves space for in `label` and `metadata_uri`
+const MAX_LABEL_LEN: usize = 32;
+const MAX_METADATA_URI_LEN: usize = 200;
+// Rent-exempt minimum under the default rent: (128 + data_len) * 3480 * 2
+const LAMPORTS_PER_BYTE: u64 = 6960;
+
+/// Returns the size of the current layout, `UserAddressLookupTable::SIZE` in the program:
+/// every field of `wrapper` serialized, with the strings at their maximum lengths.
+fn wrapper_size(wrapper: &UserAddressLookupTable) -> usize {
+    let mut wrapper = wrapper.clone();
+    wrapper.label = "a".repeat(MAX_LABEL_LEN);
+    wrapper.metadata_uri = "a".repeat(MAX_METADATA_URI_LEN);
+    borsh::to_vec(&wrapper)
+        .expect("UserAddressLookupTable should serialize")
+        .len()
+}
+
+/// Rewrites a freshly created wrapper into the legacy layout, as left by the program
+/// before the `version` field was added.
+fn make_legacy(ctx: &MolluskContextHelper, user_address_lookup_table: &Pubkey) {
+    let mut wrapper_account = ctx
+        .get_account(user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    wrapper_account.data.truncate(LEGACY_SIZE);
+    wrapper_account.lamports = (128 + LEGACY_SIZE as u64) * LAMPORTS_PER_BYTE;
+    ctx.add_account(user_address_lookup_table, wrapper_account);
+}
+
+fn propose_authority(
+    ctx: &MolluskContextHelper,
+    signer: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+) -> bool {
+    let instruction = ProposeAuthorityBuilder::new()
+        .signer(*signer)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .new_authority(Pubkey::new_unique())
+        .instruction();
+    ctx.process_instruction(&instruction).is_ok()
+}
+
+#[test]
+fn test_legacy_wrapper_is_readable() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    make_legacy(&ctx, &user_address_lookup_table);
+
+    let instruction = GetLutStatusBuilder::new()
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("GetLutStatus should read a legacy wrapper");
+    let status = LutStatusInfo::try_from_slice(&result.return_data)
+        .expect("Return data should be a LutStatusInfo");
+    assert_eq!(status.status, LutStatus::Active);
+    assert_eq!(
+        status.ready_at_slot,
+        recent_slot + 1 + DEFAULT_COOLDOWN_SLOTS
+    );
+}
+
+#[test]
+fn test_legacy_wrapper_reports_deactivation() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    make_legacy(&ctx, &user_address_lookup_table);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Deactivate should succeed: {:?}", result);
+
+    let instruction = GetLutStatusBuilder::new()
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("GetLutStatus should read a legacy wrapper");
+    let status = LutStatusInfo::try_from_slice(&result.return_data)
+        .expect("Return data should be a LutStatusInfo");
+    assert!(!status.ready, "Deactivated LUT should not be ready");
+    assert_eq!(status.status, LutStatus::Deactivated);
+    assert_eq!(
+        status.deactivated_slot,
+        recent_slot + DEFAULT_COOLDOWN_SLOTS + 2
+    );
+}
+
+#[test]
+fn test_legacy_wrapper_requires_migration_to_write() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+    make_legacy(&ctx, &user_address_lookup_table);
+
+    assert!(
+        !propose_authority(&ctx, &signer, &user_address_lookup_table),
+        "Writing a legacy wrapper should fail with WrapperNeedsMigration"
+    );
+
+    let signer_before = ctx.get_balance(&signer).unwrap();
+    let wrapper_before = ctx.get_balance(&user_address_lookup_table).unwrap();
+
+    let instruction = MigrateWrapperBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "MigrateWrapper should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let rent_paid = wrapper_account.lamports - wrapper_before;
+    assert!(rent_paid > 0, "Owner should pay the rent difference");
+    assert_eq!(ctx.get_balance(&signer).unwrap(), signer_before - rent_paid);
+
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("Migrated wrapper should deserialize");
+    assert_eq!(wrapper_account.data.len(), wrapper_size(&wrapper));
+    assert_eq!(wrapper.version, 1);
+    assert_eq!(wrapper.creator, signer);
+    assert_eq!(wrapper.status, LutStatus::Active);
+
+    assert!(
+        propose_authority(&ctx, &signer, &user_address_lookup_table),
+        "Writing a migrated wrapper should succeed"
+    );
+}
+
+#[test]
+fn test_legacy_wrapper_can_be_deactivated_and_closed() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    make_legacy(&ctx, &user_address_lookup_table);
+
+    ctx.warp_to_slot(recent_slot + 10);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "DeactivateAddressLookupTable should not require migration: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    assert_eq!(
+        wrapper_account.data.len(),
+        LEGACY_SIZE,
+        "Wrapper should stay in the legacy layout"
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseAddressLookupTable should succeed on a legacy wrapper: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_migrate_current_wrapper_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = MigrateWrapperBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "MigrateWrapper on a current wrapper should fail with WrapperAlreadyMigrated"
+    );
+}
+
+#[test]
+fn test_migrate_wrong_signer_fails() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let wrong_signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&wrong_signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+    make_legacy(&ctx, &user_address_lookup_table);
+
+    let instruction = MigrateWrapperBuilder::new()
+        .signer(wrong_signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "MigrateWrapper with wrong signer should fail"
+    );
+}
@@ -0,0 +1,66 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::ProposeAuthorityBuilder;
+use crate::common::helpers::{create_context, create_lut};
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_propose_authority_sets_pending_authority() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = ProposeAuthorityBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .new_authority(new_authority)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "ProposeAuthority should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.pending_authority, new_authority);
+    assert_eq!(wrapper.signer, signer, "Authority should not change yet");
+}
+
+#[test]
+fn test_propose_authority_rejects_non_authority() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let attacker = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&attacker, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = ProposeAuthorityBuilder::new()
+        .signer(attacker)
+        .user_address_lookup_table(user_address_lookup_table)
+        .new_authority(attacker)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ProposeAuthority from a non-authority should fail"
+    );
+}
@@ -0,0 +1,201 @@
+use crate::codama_rust_luts::accounts::LutSet;
+use crate::codama_rust_luts::instructions::{
+    CloseAddressLookupTableBuilder, DeactivateAddressLookupTableBuilder, ExtendLutSetBuilder,
+    RemoveLutSetMemberBuilder,
+};
+use crate::common::helpers::{
+    add_lut_set_member, create_context, create_lut, create_lut_set, DEFAULT_COOLDOWN_SLOTS,
+};
+use crate::common::pda::{get_config_pda, get_lut_counter_pda};
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+fn remove_lut_set_member(
+    ctx: &MolluskContextHelper,
+    signer: &Pubkey,
+    lut_set: &Pubkey,
+    member: &Pubkey,
+) {
+    let instruction = RemoveLutSetMemberBuilder::new()
+        .signer(*signer)
+        .lut_set(*lut_set)
+        .member(*member)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "RemoveLutSetMember should succeed: {:?}",
+        result
+    );
+}
+
+fn get_lut_set(ctx: &MolluskContextHelper, lut_set: &Pubkey) -> LutSet {
+    let lut_set_account = ctx
+        .get_account(lut_set)
+        .expect("LutSet account should exist");
+    LutSet::from_bytes(&lut_set_account.data).expect("LutSet should deserialize")
+}
+
+#[test]
+fn test_remove_lut_set_member_keeps_fill_order() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (first, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+    let (second, _) = create_lut(&mut ctx, &signer, 1, recent_slot + 5);
+    let (third, _) = create_lut(&mut ctx, &signer, 2, recent_slot + 10);
+    add_lut_set_member(&ctx, &signer, &lut_set, &first);
+    add_lut_set_member(&ctx, &signer, &lut_set, &second);
+    add_lut_set_member(&ctx, &signer, &lut_set, &third);
+
+    remove_lut_set_member(&ctx, &signer, &lut_set, &second);
+
+    let lut_set = get_lut_set(&ctx, &lut_set);
+    assert_eq!(lut_set.member_count, 2);
+    assert_eq!(lut_set.members[0], first);
+    assert_eq!(lut_set.members[1], third);
+    assert_eq!(lut_set.members[2], Pubkey::default());
+}
+
+#[test]
+fn test_remove_closed_member_unblocks_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (first, first_lut) = create_lut(&mut ctx, &signer, 0, recent_slot);
+    let (second, second_lut) = create_lut(&mut ctx, &signer, 1, recent_slot + 5);
+    add_lut_set_member(&ctx, &signer, &lut_set, &first);
+    add_lut_set_member(&ctx, &signer, &lut_set, &second);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(first_lut)
+        .user_address_lookup_table(first)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "DeactivateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 10);
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(first_lut)
+        .user_address_lookup_table(first)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CloseAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    ctx.warp_to_slot(recent_slot + 513 + 10 + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let extend_instruction = |members: &[(Pubkey, Pubkey)]| {
+        ExtendLutSetBuilder::new()
+            .signer(signer)
+            .config(get_config_pda().0)
+            .lut_set(lut_set)
+            .addresses(vec![Pubkey::new_unique()])
+            .add_remaining_accounts(
+                &members
+                    .iter()
+                    .flat_map(|(wrapper, lut)| {
+                        [
+                            AccountMeta::new(*wrapper, false),
+                            AccountMeta::new(*lut, false),
+                        ]
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .instruction()
+    };
+
+    let result = ctx.process_instruction(&extend_instruction(&[
+        (first, first_lut),
+        (second, second_lut),
+    ]));
+    assert!(
+        result.is_err(),
+        "ExtendLutSet with a closed member should fail"
+    );
+
+    remove_lut_set_member(&ctx, &signer, &lut_set, &first);
+
+    let result = ctx.process_instruction(&extend_instruction(&[(second, second_lut)]));
+    assert!(
+        result.is_ok(),
+        "ExtendLutSet after removing the closed member should succeed: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_remove_lut_set_member_fails_for_non_member() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    let instruction = RemoveLutSetMemberBuilder::new()
+        .signer(signer)
+        .lut_set(lut_set)
+        .member(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "RemoveLutSetMember for a wrapper outside the set should fail with InvalidLutSetMember"
+    );
+}
+
+#[test]
+fn test_remove_lut_set_member_fails_for_other_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let lut_set = create_lut_set(&ctx, &signer, 0);
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+    add_lut_set_member(&ctx, &signer, &lut_set, &user_address_lookup_table);
+
+    let instruction = RemoveLutSetMemberBuilder::new()
+        .signer(other)
+        .lut_set(lut_set)
+        .member(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "RemoveLutSetMember by someone other than the set owner should fail"
+    );
+}
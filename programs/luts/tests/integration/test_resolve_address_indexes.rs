@@ -0,0 +1,106 @@
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, ResolveAddressIndexesBuilder,
+};
+use crate::common::helpers::create_context;
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use borsh::BorshDeserialize;
+use mollusk_helper::MolluskContextHelper;
+use solana_pubkey::Pubkey;
+
+const ADDRESS_NOT_FOUND: u16 = u16::MAX;
+
+fn create_lut_with_addresses(
+    ctx: &mut MolluskContextHelper,
+    signer: &Pubkey,
+    id: u64,
+    recent_slot: u64,
+    initial_addresses: Vec<Pubkey>,
+) -> (Pubkey, Pubkey) {
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .initial_addresses(initial_addresses)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    (user_address_lookup_table, address_lookup_table)
+}
+
+#[test]
+fn test_resolve_address_indexes() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+    let initial: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let missing = Pubkey::new_unique();
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut_with_addresses(&mut ctx, &signer, 0, recent_slot, initial.clone());
+
+    let instruction = ResolveAddressIndexesBuilder::new()
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![initial[2], missing, initial[0]])
+        .instruction();
+
+    let result = ctx
+        .process_instruction(&instruction)
+        .expect("ResolveAddressIndexes should succeed");
+    let indexes = Vec::<u16>::try_from_slice(&result.return_data)
+        .expect("Return data should be a list of indexes");
+    assert_eq!(indexes, vec![2, ADDRESS_NOT_FOUND, 0]);
+}
+
+#[test]
+fn test_resolve_address_indexes_rejects_too_many_addresses() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) = create_lut_with_addresses(
+        &mut ctx,
+        &signer,
+        0,
+        recent_slot,
+        vec![Pubkey::new_unique()],
+    );
+
+    let instruction = ResolveAddressIndexesBuilder::new()
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses((0..257).map(|_| Pubkey::new_unique()).collect())
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ResolveAddressIndexes with more than 256 addresses should fail"
+    );
+}
@@ -0,0 +1,96 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    ExtendAddressLookupTableBuilder, GrantDelegateBuilder, RevokeDelegateBuilder,
+};
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::get_config_pda;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+const COOLDOWN_SLOTS: u64 = 15;
+
+#[test]
+fn test_revoke_rejects_unknown_delegate() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = RevokeDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(Pubkey::new_unique())
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "RevokeDelegate for an unknown key should fail with DelegateNotFound"
+    );
+}
+
+#[test]
+fn test_revoke_removes_extend_access() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&delegate, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = GrantDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(delegate)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "GrantDelegate should succeed: {:?}", result);
+
+    let instruction = RevokeDelegateBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .delegate(delegate)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "RevokeDelegate should succeed: {:?}",
+        result
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.delegates[0].key, Pubkey::default());
+
+    ctx.warp_to_slot(recent_slot + COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(delegate)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Revoked delegate should not be able to extend"
+    );
+}
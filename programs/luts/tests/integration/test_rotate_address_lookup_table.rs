@@ -0,0 +1,153 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    CloseAddressLookupTableBuilder, ExtendAddressLookupTableBuilder,
+    RotateAddressLookupTableBuilder,
+};
+use crate::common::helpers::{create_context, create_lut, rotate_lut, DEFAULT_COOLDOWN_SLOTS};
+use crate::common::pda::{derive_address_lookup_table, get_config_pda, get_lut_counter_pda};
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_rotate_swaps_lut_and_bumps_generation() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let rotate_slot = recent_slot + 10;
+    let new_address_lookup_table = rotate_lut(
+        &mut ctx,
+        &signer,
+        &user_address_lookup_table,
+        &address_lookup_table,
+        rotate_slot,
+    );
+    assert_ne!(new_address_lookup_table, address_lookup_table);
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("Wrapper account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("Wrapper should deserialize");
+    assert_eq!(wrapper.address_lookup_table, new_address_lookup_table);
+    assert_eq!(wrapper.previous_address_lookup_table, address_lookup_table);
+    assert_eq!(wrapper.generation, 1);
+    assert_eq!(wrapper.size, 0);
+
+    ctx.warp_to_slot(rotate_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(new_address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend of the rotated-in LUT should succeed: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_rotate_fails_while_previous_lut_is_open() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let new_address_lookup_table = rotate_lut(
+        &mut ctx,
+        &signer,
+        &user_address_lookup_table,
+        &address_lookup_table,
+        recent_slot + 10,
+    );
+
+    let next_slot = recent_slot + 20;
+    ctx.warp_to_slot(next_slot);
+    ctx.warp_to_slot(next_slot + 1);
+
+    let instruction = RotateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(new_address_lookup_table)
+        .new_address_lookup_table(
+            derive_address_lookup_table(&user_address_lookup_table, next_slot).0,
+        )
+        .user_address_lookup_table(user_address_lookup_table)
+        .recent_slot(next_slot)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Rotate while the previous LUT is still open should fail with PreviousLutPending"
+    );
+
+    let instruction = CloseAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(new_address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(Some(get_lut_counter_pda(&signer).0))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Closing the wrapper while the previous LUT is still open should fail"
+    );
+}
+
+#[test]
+fn test_rotate_fails_for_non_owner() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let rotate_slot = recent_slot + 10;
+    ctx.warp_to_slot(rotate_slot);
+    ctx.warp_to_slot(rotate_slot + 1);
+
+    let instruction = RotateAddressLookupTableBuilder::new()
+        .signer(other)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .new_address_lookup_table(
+            derive_address_lookup_table(&user_address_lookup_table, rotate_slot).0,
+        )
+        .user_address_lookup_table(user_address_lookup_table)
+        .recent_slot(rotate_slot)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "RotateAddressLookupTable from a non-owner should fail"
+    );
+}
@@ -0,0 +1,278 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    ExtendAddressLookupTableBuilder, ExtendAddressLookupTableWithArgsBuilder,
+    SetOwnerAllowlistBuilder,
+};
+use crate::common::helpers::{create_context, create_lut, DEFAULT_COOLDOWN_SLOTS};
+use crate::common::pda::get_config_pda;
+use mollusk_helper::MolluskContextHelper;
+use solana_account::Account;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_pubkey::pubkey!("11111111111111111111111111111111");
+
+fn set_owner_allowlist(
+    ctx: &MolluskContextHelper,
+    signer: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+    allowed_owners: Vec<Pubkey>,
+) -> bool {
+    let instruction = SetOwnerAllowlistBuilder::new()
+        .signer(*signer)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .allowed_owners(allowed_owners)
+        .instruction();
+    ctx.process_instruction(&instruction).is_ok()
+}
+
+/// Adds an account owned by `owner` and returns its address.
+fn add_owned_account(ctx: &MolluskContextHelper, owner: &Pubkey) -> Pubkey {
+    let key = Pubkey::new_unique();
+    ctx.add_account(
+        &key,
+        Account {
+            lamports: 1_000_000,
+            owner: *owner,
+            ..Account::default()
+        },
+    );
+    key
+}
+
+fn extend(
+    ctx: &MolluskContextHelper,
+    signer: &Pubkey,
+    user_address_lookup_table: &Pubkey,
+    address_lookup_table: &Pubkey,
+    addresses: &[Pubkey],
+) -> bool {
+    let mut builder = ExtendAddressLookupTableBuilder::new();
+    builder
+        .signer(*signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(*address_lookup_table)
+        .user_address_lookup_table(*user_address_lookup_table);
+    for address in addresses {
+        builder.add_remaining_account(AccountMeta::new_readonly(*address, false));
+    }
+    ctx.process_instruction(&builder.instruction()).is_ok()
+}
+
+#[test]
+fn test_set_owner_allowlist() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let amm_program = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    assert!(
+        set_owner_allowlist(&ctx, &signer, &user_address_lookup_table, vec![amm_program]),
+        "SetOwnerAllowlist should succeed"
+    );
+
+    let wrapper_account = ctx
+        .get_account(&user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    let wrapper = UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize");
+    assert_eq!(wrapper.allowed_owners[0], amm_program);
+    assert_eq!(wrapper.allowed_owner_count, 1);
+}
+
+#[test]
+fn test_extend_checks_owner_allowlist() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let amm_program = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    assert!(set_owner_allowlist(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        vec![amm_program]
+    ));
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let pool = add_owned_account(&ctx, &amm_program);
+    let garbage = add_owned_account(&ctx, &Pubkey::new_unique());
+
+    assert!(
+        !extend(
+            &ctx,
+            &signer,
+            &user_address_lookup_table,
+            &address_lookup_table,
+            &[pool, garbage]
+        ),
+        "Extend with an account owned by another program should fail with OwnerNotAllowed"
+    );
+    assert!(
+        extend(
+            &ctx,
+            &signer,
+            &user_address_lookup_table,
+            &address_lookup_table,
+            &[pool]
+        ),
+        "Extend with an account owned by an allowed program should succeed"
+    );
+}
+
+#[test]
+fn test_extend_rejects_system_owned_address_unless_allowed() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let amm_program = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    assert!(set_owner_allowlist(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        vec![amm_program]
+    ));
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    // An address with no account is owned by the System Program, whose id is also the
+    // default pubkey held by unused allowlist slots.
+    let missing = Pubkey::new_unique();
+    assert!(
+        !extend(
+            &ctx,
+            &signer,
+            &user_address_lookup_table,
+            &address_lookup_table,
+            &[missing]
+        ),
+        "Extend with an address that has no account should fail with OwnerNotAllowed"
+    );
+
+    assert!(set_owner_allowlist(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        vec![amm_program, SYSTEM_PROGRAM_ID]
+    ));
+    assert!(
+        extend(
+            &ctx,
+            &signer,
+            &user_address_lookup_table,
+            &address_lookup_table,
+            &[missing]
+        ),
+        "Extend should succeed once the System Program is allowed"
+    );
+}
+
+#[test]
+fn test_extend_with_args_refused_while_allowlist_set() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+    assert!(set_owner_allowlist(
+        &ctx,
+        &signer,
+        &user_address_lookup_table,
+        vec![Pubkey::new_unique()]
+    ));
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique()])
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "ExtendAddressLookupTableWithArgs should fail while an allowlist is set"
+    );
+
+    assert!(
+        set_owner_allowlist(&ctx, &signer, &user_address_lookup_table, vec![]),
+        "Clearing the allowlist should succeed"
+    );
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "ExtendAddressLookupTableWithArgs should succeed once the allowlist is cleared: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_set_owner_allowlist_rejects_too_many_owners() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let owners = (0..5).map(|_| Pubkey::new_unique()).collect();
+    assert!(
+        !set_owner_allowlist(&ctx, &signer, &user_address_lookup_table, owners),
+        "SetOwnerAllowlist with more than 4 owners should fail with MaxAllowedOwnersExceeded"
+    );
+}
+
+#[test]
+fn test_set_owner_allowlist_rejects_non_authority() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let attacker = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&attacker, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    assert!(
+        !set_owner_allowlist(
+            &ctx,
+            &attacker,
+            &user_address_lookup_table,
+            vec![Pubkey::new_unique()]
+        ),
+        "SetOwnerAllowlist from a non-authority should fail"
+    );
+}
@@ -0,0 +1,171 @@
+use crate::codama_rust_luts::accounts::Config;
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, DeactivateAddressLookupTableBuilder,
+    ExtendAddressLookupTableBuilder, SetPausedBuilder,
+};
+use crate::common::helpers::{
+    create_context, create_lut, guardian, upgrade_authority, DEFAULT_COOLDOWN_SLOTS,
+};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+fn set_paused(ctx: &MolluskContextHelper, authority: &Pubkey, paused: bool) {
+    let instruction = SetPausedBuilder::new()
+        .authority(*authority)
+        .config(get_config_pda().0)
+        .paused(paused)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "SetPaused should succeed: {:?}", result);
+}
+
+fn is_paused(ctx: &MolluskContextHelper) -> bool {
+    let config_account = ctx
+        .get_account(&get_config_pda().0)
+        .expect("Config account should exist");
+    Config::from_bytes(&config_account.data)
+        .expect("Config should deserialize")
+        .paused
+}
+
+#[test]
+fn test_pause_blocks_create_and_extend_but_not_deactivate() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    set_paused(&ctx, &upgrade_authority(), true);
+    assert!(is_paused(&ctx));
+
+    let id: u64 = 1;
+    let (new_user_address_lookup_table, _) = get_user_address_lookup_table_pda(&signer, id);
+    let (new_address_lookup_table, _) =
+        derive_address_lookup_table(&new_user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(new_address_lookup_table)
+        .user_address_lookup_table(new_user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable while paused should fail with ProgramPaused"
+    );
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend while paused should fail with ProgramPaused"
+    );
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Deactivate should keep working while paused: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_unpause_restores_extend() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    set_paused(&ctx, &upgrade_authority(), true);
+    set_paused(&ctx, &upgrade_authority(), false);
+    assert!(!is_paused(&ctx));
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend after unpausing should succeed: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_guardian_can_pause_but_not_unpause() {
+    let ctx = create_context();
+
+    set_paused(&ctx, &guardian(), true);
+    assert!(is_paused(&ctx));
+
+    let instruction = SetPausedBuilder::new()
+        .authority(guardian())
+        .config(get_config_pda().0)
+        .paused(false)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_err(), "Guardian should not be able to unpause");
+}
+
+#[test]
+fn test_set_paused_rejects_other_keys() {
+    let ctx = create_context();
+
+    let other = Pubkey::new_unique();
+
+    let instruction = SetPausedBuilder::new()
+        .authority(other)
+        .config(get_config_pda().0)
+        .paused(true)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "SetPaused from a key other than the admin or guardian should fail"
+    );
+}
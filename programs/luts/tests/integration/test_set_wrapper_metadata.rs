@@ -0,0 +1,163 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, SetWrapperMetadataBuilder,
+};
+use crate::common::helpers::{create_context, create_lut};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use mollusk_helper::MolluskContextHelper;
+use solana_pubkey::Pubkey;
+
+fn get_wrapper(
+    ctx: &MolluskContextHelper,
+    user_address_lookup_table: &Pubkey,
+) -> UserAddressLookupTable {
+    let wrapper_account = ctx
+        .get_account(user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize")
+}
+
+#[test]
+fn test_create_with_metadata() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(&signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .label("swap-routes".to_string())
+        .metadata_uri("https://example.com/luts/0.json".to_string())
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable with metadata should succeed: {:?}",
+        result
+    );
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(wrapper.label, "swap-routes");
+    assert_eq!(wrapper.metadata_uri, "https://example.com/luts/0.json");
+}
+
+#[test]
+fn test_set_wrapper_metadata() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(wrapper.label, "");
+    assert_eq!(wrapper.metadata_uri, "");
+
+    let instruction = SetWrapperMetadataBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .label("orders".to_string())
+        .metadata_uri("ipfs://orders".to_string())
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "SetWrapperMetadata should succeed: {:?}",
+        result
+    );
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(wrapper.label, "orders");
+    assert_eq!(wrapper.metadata_uri, "ipfs://orders");
+
+    // Omitted fields are left unchanged
+    let instruction = SetWrapperMetadataBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .label(String::new())
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "SetWrapperMetadata should succeed: {:?}",
+        result
+    );
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(wrapper.label, "");
+    assert_eq!(wrapper.metadata_uri, "ipfs://orders");
+}
+
+#[test]
+fn test_set_wrapper_metadata_rejects_long_label() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = SetWrapperMetadataBuilder::new()
+        .signer(signer)
+        .user_address_lookup_table(user_address_lookup_table)
+        .label("a".repeat(33))
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "SetWrapperMetadata with a label over 32 bytes should fail with WrapperMetadataTooLong"
+    );
+}
+
+#[test]
+fn test_set_wrapper_metadata_rejects_non_authority() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let attacker = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&attacker, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = SetWrapperMetadataBuilder::new()
+        .signer(attacker)
+        .user_address_lookup_table(user_address_lookup_table)
+        .label("hijacked".to_string())
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "SetWrapperMetadata from a non-authority should fail"
+    );
+}
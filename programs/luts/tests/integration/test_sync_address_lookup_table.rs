@@ -0,0 +1,182 @@
+use crate::codama_rust_luts::accounts::UserAddressLookupTable;
+use crate::codama_rust_luts::instructions::{
+    DeactivateAddressLookupTableBuilder, ExtendAddressLookupTableWithArgsBuilder,
+    SyncAddressLookupTableBuilder,
+};
+use crate::codama_rust_luts::types::LutStatus;
+use crate::common::helpers::{create_context, create_lut, DEFAULT_COOLDOWN_SLOTS};
+use crate::common::pda::get_config_pda;
+use mollusk_helper::MolluskContextHelper;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+// discriminator + bump + signer
+const SIZE_OFFSET: usize = 8 + 1 + 32;
+
+fn get_wrapper(
+    ctx: &MolluskContextHelper,
+    user_address_lookup_table: &Pubkey,
+) -> UserAddressLookupTable {
+    let wrapper_account = ctx
+        .get_account(user_address_lookup_table)
+        .expect("UserAddressLookupTable account should exist");
+    UserAddressLookupTable::from_bytes(&wrapper_account.data)
+        .expect("UserAddressLookupTable should deserialize")
+}
+
+fn sync_instruction(
+    user_address_lookup_table: &Pubkey,
+    address_lookup_table: &Pubkey,
+) -> Instruction {
+    SyncAddressLookupTableBuilder::new()
+        .config(get_config_pda().0)
+        .address_lookup_table(*address_lookup_table)
+        .user_address_lookup_table(*user_address_lookup_table)
+        .instruction()
+}
+
+#[test]
+fn test_sync_rewrites_drifted_size() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let mut wrapper_account = ctx.get_account(&user_address_lookup_table).unwrap();
+    wrapper_account.data[SIZE_OFFSET..SIZE_OFFSET + 8].copy_from_slice(&42u64.to_le_bytes());
+    ctx.add_account(&user_address_lookup_table, wrapper_account);
+    assert_eq!(get_wrapper(&ctx, &user_address_lookup_table).size, 42);
+
+    let result = ctx.process_instruction(&sync_instruction(
+        &user_address_lookup_table,
+        &address_lookup_table,
+    ));
+    assert!(
+        result.is_ok(),
+        "SyncAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(wrapper.size, 0, "Size should match the native LUT");
+    assert_eq!(wrapper.status, LutStatus::Active);
+}
+
+#[test]
+fn test_sync_rewrites_drifted_slots() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let extend_slot = recent_slot + DEFAULT_COOLDOWN_SLOTS + 2;
+    ctx.warp_to_slot(extend_slot);
+
+    let instruction = ExtendAddressLookupTableWithArgsBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .addresses(vec![Pubkey::new_unique()])
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Extend should succeed: {:?}", result);
+
+    let mut wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    wrapper.last_updated_slot = recent_slot;
+    wrapper.ready_at_slot = recent_slot;
+    let data = borsh::to_vec(&wrapper).expect("UserAddressLookupTable should serialize");
+    let mut wrapper_account = ctx.get_account(&user_address_lookup_table).unwrap();
+    wrapper_account.data[..data.len()].copy_from_slice(&data);
+    ctx.add_account(&user_address_lookup_table, wrapper_account);
+
+    ctx.warp_to_slot(extend_slot + 1);
+
+    let result = ctx.process_instruction(&sync_instruction(
+        &user_address_lookup_table,
+        &address_lookup_table,
+    ));
+    assert!(
+        result.is_ok(),
+        "SyncAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(
+        wrapper.last_updated_slot, extend_slot,
+        "last_updated_slot should match the native LUT's last extend"
+    );
+    assert_eq!(wrapper.ready_at_slot, extend_slot + DEFAULT_COOLDOWN_SLOTS);
+}
+
+#[test]
+fn test_sync_keeps_deactivation() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    let instruction = DeactivateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .instruction();
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Deactivate should succeed: {:?}", result);
+    let deactivated_slot = get_wrapper(&ctx, &user_address_lookup_table).deactivated_slot;
+
+    let result = ctx.process_instruction(&sync_instruction(
+        &user_address_lookup_table,
+        &address_lookup_table,
+    ));
+    assert!(
+        result.is_ok(),
+        "SyncAddressLookupTable should succeed: {:?}",
+        result
+    );
+
+    let wrapper = get_wrapper(&ctx, &user_address_lookup_table);
+    assert_eq!(wrapper.status, LutStatus::Deactivated);
+    assert_eq!(wrapper.deactivated_slot, deactivated_slot);
+}
+
+#[test]
+fn test_sync_rejects_mismatched_lut() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let (user_address_lookup_table, _) = create_lut(&mut ctx, &signer, 0, recent_slot);
+    let (_, other_address_lookup_table) = create_lut(&mut ctx, &signer, 1, recent_slot + 5);
+
+    let result = ctx.process_instruction(&sync_instruction(
+        &user_address_lookup_table,
+        &other_address_lookup_table,
+    ));
+    assert!(
+        result.is_err(),
+        "SyncAddressLookupTable with another wrapper's LUT should fail"
+    );
+}
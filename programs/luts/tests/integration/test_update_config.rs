@@ -0,0 +1,292 @@
+use crate::codama_rust_luts::accounts::Config;
+use crate::codama_rust_luts::instructions::{
+    CreateAddressLookupTableBuilder, ExtendAddressLookupTableBuilder, UpdateConfigBuilder,
+};
+use crate::common::helpers::{
+    create_context, create_lut, upgrade_authority, DEFAULT_COOLDOWN_SLOTS,
+};
+use crate::common::pda::{
+    derive_address_lookup_table, get_config_pda, get_lut_counter_pda,
+    get_user_address_lookup_table_pda,
+};
+use solana_instruction::AccountMeta;
+use solana_pubkey::Pubkey;
+
+#[test]
+fn test_update_config_rejects_non_admin() {
+    let ctx = create_context();
+
+    let other = Pubkey::new_unique();
+    ctx.fund_account(&other, 10_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(other)
+        .config(get_config_pda().0)
+        .cooldown_slots(30)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "UpdateConfig from a key other than the admin should fail"
+    );
+}
+
+#[test]
+fn test_update_config_transfers_admin() {
+    let ctx = create_context();
+
+    let new_admin = Pubkey::new_unique();
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .new_admin(new_admin)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    let config_account = ctx
+        .get_account(&get_config_pda().0)
+        .expect("Config account should exist");
+    let config = Config::from_bytes(&config_account.data).expect("Config should deserialize");
+    assert_eq!(config.admin, new_admin);
+    assert_eq!(config.cooldown_slots, DEFAULT_COOLDOWN_SLOTS);
+}
+
+#[test]
+fn test_update_config_rejects_zero_cooldown() {
+    let ctx = create_context();
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .cooldown_slots(0)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "UpdateConfig with a zero cooldown should fail with InvalidConfig"
+    );
+}
+
+#[test]
+fn test_extend_uses_configured_cooldown() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+    let cooldown_slots: u64 = 40;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .cooldown_slots(cooldown_slots)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    let (user_address_lookup_table, address_lookup_table) =
+        create_lut(&mut ctx, &signer, id, recent_slot);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "Extend before the configured cooldown should fail with LutNotReady"
+    );
+
+    ctx.warp_to_slot(recent_slot + cooldown_slots + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "Extend after the configured cooldown should succeed: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_create_enforces_max_luts_per_signer() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let recent_slot: u64 = 100;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .max_luts_per_signer(1)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    create_lut(&mut ctx, &signer, 0, recent_slot);
+
+    let id: u64 = 1;
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(&signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable beyond max_luts_per_signer should fail"
+    );
+}
+
+#[test]
+fn test_update_config_rejects_fee_without_treasury() {
+    let ctx = create_context();
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .create_fee_lamports(1_000)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "UpdateConfig setting a fee without a treasury should fail with InvalidConfig"
+    );
+}
+
+#[test]
+fn test_create_and_extend_pay_fees_to_treasury() {
+    let mut ctx = create_context();
+
+    let signer = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let id: u64 = 0;
+    let recent_slot: u64 = 100;
+    let create_fee: u64 = 1_000_000;
+    let fee_per_address: u64 = 5_000;
+
+    ctx.fund_account(&signer, 10_000_000_000);
+    ctx.fund_account(&treasury, 1_000_000_000);
+
+    let instruction = UpdateConfigBuilder::new()
+        .admin(upgrade_authority())
+        .config(get_config_pda().0)
+        .treasury(treasury)
+        .create_fee_lamports(create_fee)
+        .extend_fee_lamports_per_address(fee_per_address)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "UpdateConfig should succeed: {:?}", result);
+
+    ctx.warp_to_slot(recent_slot);
+    ctx.warp_to_slot(recent_slot + 1);
+
+    let (user_address_lookup_table, _) = get_user_address_lookup_table_pda(&signer, id);
+    let (address_lookup_table, _) =
+        derive_address_lookup_table(&user_address_lookup_table, recent_slot);
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable without the treasury should fail with MissingTreasury"
+    );
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .treasury(Some(Pubkey::new_unique()))
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_err(),
+        "CreateAddressLookupTable with the wrong treasury should fail with InvalidTreasury"
+    );
+
+    let instruction = CreateAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .treasury(Some(treasury))
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .lut_counter(get_lut_counter_pda(&signer).0)
+        .recent_slot(recent_slot)
+        .id(id)
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(
+        result.is_ok(),
+        "CreateAddressLookupTable should succeed: {:?}",
+        result
+    );
+    let balance = ctx.get_account(&treasury).unwrap().lamports;
+    assert_eq!(balance, 1_000_000_000 + create_fee);
+
+    ctx.warp_to_slot(recent_slot + DEFAULT_COOLDOWN_SLOTS + 2);
+
+    let instruction = ExtendAddressLookupTableBuilder::new()
+        .signer(signer)
+        .config(get_config_pda().0)
+        .treasury(Some(treasury))
+        .address_lookup_table(address_lookup_table)
+        .user_address_lookup_table(user_address_lookup_table)
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .add_remaining_account(AccountMeta::new_readonly(Pubkey::new_unique(), false))
+        .instruction();
+
+    let result = ctx.process_instruction(&instruction);
+    assert!(result.is_ok(), "Extend should succeed: {:?}", result);
+    let balance = ctx.get_account(&treasury).unwrap().lamports;
+    assert_eq!(balance, 1_000_000_000 + create_fee + 2 * fee_per_address);
+}
@@ -8,8 +8,34 @@ pub use codama_rust_luts::LUTS_ID;
 pub mod common;
 
 mod integration {
+    pub mod test_accept_authority;
+    pub mod test_add_lut_set_member;
     pub mod test_close_address_lookup_table;
+    pub mod test_close_orphaned_wrapper;
+    pub mod test_close_previous_address_lookup_table;
     pub mod test_create_address_lookup_table;
+    pub mod test_create_address_lookup_table_with_namespace;
+    pub mod test_create_lut_set;
     pub mod test_deactivate_address_lookup_table;
     pub mod test_extend_address_lookup_table;
+    pub mod test_extend_address_lookup_table_with_args;
+    pub mod test_extend_address_lookup_table_with_mode;
+    pub mod test_extend_lut_set;
+    pub mod test_freeze_address_lookup_table;
+    pub mod test_get_lut_status;
+    pub mod test_grant_delegate;
+    pub mod test_initialize_config;
+    pub mod test_initialize_lut_registry;
+    pub mod test_mark_in_use;
+    pub mod test_migrate_wrapper;
+    pub mod test_propose_authority;
+    pub mod test_remove_lut_set_member;
+    pub mod test_resolve_address_indexes;
+    pub mod test_revoke_delegate;
+    pub mod test_rotate_address_lookup_table;
+    pub mod test_set_owner_allowlist;
+    pub mod test_set_paused;
+    pub mod test_set_wrapper_metadata;
+    pub mod test_sync_address_lookup_table;
+    pub mod test_update_config;
 }
@@ -15,8 +15,46 @@ describe("luts", () => {
     "AddressLookupTab1e1111111111111111111111111"
   );
 
+  const BPF_LOADER_UPGRADEABLE_PROGRAM = new PublicKey(
+    "BPFLoaderUpgradeab1e11111111111111111111111"
+  );
+
+  const [configPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("Config")],
+    program.programId
+  );
+
   let lutId = new anchor.BN(0);
 
+  before(async () => {
+    const existing = await provider.connection.getAccountInfo(configPda);
+    if (existing) {
+      return;
+    }
+    const [programData] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM
+    );
+    await program.methods
+      .initializeConfig({
+        admin: signer,
+        guardian: PublicKey.default,
+        cooldownSlots: new anchor.BN(15),
+        maxLutsPerSigner: new anchor.BN(0),
+        treasury: PublicKey.default,
+        createFeeLamports: new anchor.BN(0),
+        extendFeeLamportsPerAddress: new anchor.BN(0),
+      })
+      .accountsStrict({
+        signer,
+        systemProgram: SystemProgram.programId,
+        program: program.programId,
+        programData,
+        config: configPda,
+      })
+      .rpc();
+  });
+
   function getUserAddressLookupTablePda(
     signer: PublicKey,
     id: anchor.BN
@@ -55,14 +93,30 @@ describe("luts", () => {
       userAddressLookupTable,
       recentSlot
     );
+    const [lutCounter] = PublicKey.findProgramAddressSync(
+      [Buffer.from("LutCounter"), signer.toBuffer()],
+      program.programId
+    );
 
     await program.methods
-      .createAddressLookupTable({ recentSlot, id })
+      .createAddressLookupTable({
+        recentSlot,
+        id,
+        initialAddresses: null,
+        burstFill: null,
+        label: null,
+        metadataUri: null,
+      })
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
+        lutRegistry: null,
+        lutCounter,
         userAddressLookupTable,
         rent: SYSVAR_RENT_PUBKEY,
       })
@@ -103,6 +157,9 @@ describe("luts", () => {
         .extendAddressLookupTable()
         .accountsStrict({
           signer,
+          payer: null,
+          config: configPda,
+          treasury: null,
           systemProgram: SystemProgram.programId,
           addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
           addressLookupTable,
@@ -138,6 +195,9 @@ describe("luts", () => {
       .extendAddressLookupTable()
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
@@ -176,6 +236,9 @@ describe("luts", () => {
       .extendAddressLookupTable()
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
@@ -197,6 +260,9 @@ describe("luts", () => {
         .extendAddressLookupTable()
         .accountsStrict({
           signer,
+          payer: null,
+          config: configPda,
+          treasury: null,
           systemProgram: SystemProgram.programId,
           addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
           addressLookupTable,
@@ -241,6 +307,9 @@ describe("luts", () => {
       .extendAddressLookupTable()
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
@@ -271,6 +340,9 @@ describe("luts", () => {
       .extendAddressLookupTable()
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
@@ -307,6 +379,9 @@ describe("luts", () => {
       .extendAddressLookupTable()
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
@@ -335,6 +410,9 @@ describe("luts", () => {
       .extendAddressLookupTable()
       .accountsStrict({
         signer,
+        payer: null,
+        config: configPda,
+        treasury: null,
         systemProgram: SystemProgram.programId,
         addressLookupTableProgram: ADDRESS_LOOKUP_TABLE_PROGRAM,
         addressLookupTable,
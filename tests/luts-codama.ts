@@ -6,6 +6,8 @@ import {
   deriveAddressLookupTableAddress,
   buildCreateAddressLookupTableInstruction,
   buildExtendAddressLookupTableInstruction,
+  buildInitializeConfigInstruction,
+  getConfigAddress,
   processAndValidateTransaction,
 } from "../codama-ts-luts-custom";
 import {
@@ -23,6 +25,20 @@ describe("luts-codama", () => {
 
   let lutId = 100n;
 
+  before(async () => {
+    const [config] = getConfigAddress();
+    if (await connection.getAccountInfo(config)) {
+      return;
+    }
+    const instruction = buildInitializeConfigInstruction({
+      signer: signer.publicKey,
+      admin: signer.publicKey,
+      cooldownSlots: 15,
+      maxLutsPerSigner: 0,
+    });
+    await processAndValidateTransaction([instruction], connection, signer);
+  });
+
   async function fetchUserAddressLookupTable(
     address: PublicKey
   ): Promise<UserAddressLookupTable> {